@@ -0,0 +1,384 @@
+/*!
+# Debug Helper Derive
+
+This crate provides the `#[derive(DebugHelper)]` macro of the [`debug-helper`](https://crates.io/crates/debug-helper) crate. Enable the `derive` feature of `debug-helper` to use it.
+
+The derived implementations expand to the declarative macros of `debug-helper`, so both print exactly the same output.
+*/
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{
+    parenthesized, parse_macro_input, parse_quote, spanned::Spanned, token, Attribute, Data,
+    DataEnum, DeriveInput, Error, Expr, Fields, Ident, Index, LitStr, Member, Token,
+};
+
+#[derive(Clone, Copy)]
+enum Shape {
+    Struct,
+    Tuple,
+}
+
+impl Shape {
+    #[inline]
+    fn of(fields: &Fields) -> Shape {
+        match fields {
+            Fields::Unnamed(_) => Shape::Tuple,
+            _ => Shape::Struct,
+        }
+    }
+}
+
+/// The `#[debug_helper(...)]` attributes of a struct, an enum or a variant.
+#[derive(Default)]
+struct ItemAttributes {
+    rename:    Option<LitStr>,
+    shape:     Option<Shape>,
+    /// How enum names are printed, `name`, `path` or a prefix.
+    enum_name: Option<TokenStream2>,
+    ghosts:    Vec<(Ident, Expr)>,
+}
+
+/// The `#[debug_helper(...)]` attributes of a field.
+#[derive(Default)]
+struct FieldAttributes {
+    skip:     bool,
+    rename:   Option<LitStr>,
+    format:   Option<LitStr>,
+    modifier: Option<(Ident, TokenStream2)>,
+}
+
+fn debug_helper_attributes(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("debug_helper"))
+}
+
+fn parse_item_attributes(attrs: &[Attribute]) -> Result<ItemAttributes, Error> {
+    let mut result = ItemAttributes::default();
+
+    for attr in debug_helper_attributes(attrs) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                result.rename = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("shape") {
+                let shape: LitStr = meta.value()?.parse()?;
+
+                result.shape = Some(match shape.value().as_str() {
+                    "struct" => Shape::Struct,
+                    "tuple" => Shape::Tuple,
+                    _ => {
+                        return Err(Error::new(
+                            shape.span(),
+                            "expected `\"struct\"` or `\"tuple\"`",
+                        ))
+                    },
+                });
+            } else if meta.path.is_ident("enum_name") {
+                result.enum_name = Some(if meta.input.peek(Token![=]) {
+                    let prefix: LitStr = meta.value()?.parse()?;
+
+                    prefix.into_token_stream()
+                } else {
+                    quote_spanned!(meta.path.span()=> name)
+                });
+            } else if meta.path.is_ident("enum_path") {
+                result.enum_name = Some(quote_spanned!(meta.path.span()=> path));
+            } else if meta.path.is_ident("ghost") {
+                meta.parse_nested_meta(|ghost| {
+                    let name = ghost.path.require_ident()?.clone();
+                    let value = ghost.value()?.parse()?;
+
+                    result.ghosts.push((name, value));
+
+                    Ok(())
+                })?;
+            } else {
+                return Err(meta.error("unsupported attribute"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(result)
+}
+
+fn parse_field_attributes(attrs: &[Attribute]) -> Result<FieldAttributes, Error> {
+    let mut result = FieldAttributes::default();
+
+    for attr in debug_helper_attributes(attrs) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                result.skip = true;
+            } else if meta.path.is_ident("rename") {
+                result.rename = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("format") {
+                result.format = Some(meta.value()?.parse()?);
+            } else {
+                // other attributes are field modifiers, such as `hex(2)` or `redacted`
+                let modifier = meta.path.require_ident()?.clone();
+
+                let args = if meta.input.peek(token::Paren) {
+                    let content;
+
+                    parenthesized!(content in meta.input);
+
+                    let args: TokenStream2 = content.parse()?;
+
+                    quote!((#args))
+                } else {
+                    TokenStream2::new()
+                };
+
+                result.modifier = Some((modifier, args));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    if let Some(format) = result.format.as_ref() {
+        if result.rename.is_some() || result.modifier.is_some() {
+            return Err(Error::new(
+                format.span(),
+                "`format` cannot be used with `rename` or field modifiers",
+            ));
+        }
+    }
+
+    Ok(result)
+}
+
+/// Where the values of fields come from.
+#[derive(Clone, Copy)]
+enum Access {
+    /// `self.field` of a struct.
+    SelfField,
+    /// The bindings of an enum variant pattern. Tuple fields are bound to `_0`, `_1`, ...
+    Binding,
+}
+
+fn binding(member: &Member) -> Ident {
+    match member {
+        Member::Named(ident) => ident.clone(),
+        Member::Unnamed(index) => format_ident!("_{}", index.index, span = index.span),
+    }
+}
+
+/// Builds the field list of the declarative macros.
+fn field_list(
+    fields: &Fields,
+    shape: Shape,
+    access: Access,
+    ghosts: &[(Ident, Expr)],
+) -> Result<Vec<TokenStream2>, Error> {
+    let mut list = Vec::new();
+
+    for (i, field) in fields.iter().enumerate() {
+        let attributes = parse_field_attributes(&field.attrs)?;
+
+        if attributes.skip {
+            continue;
+        }
+
+        let member = match field.ident.as_ref() {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(i)),
+        };
+
+        let (field, value) = match access {
+            Access::SelfField => (member.to_token_stream(), quote!(self.#member)),
+            Access::Binding => {
+                let binding = binding(&member);
+
+                (binding.to_token_stream(), binding.into_token_stream())
+            },
+        };
+
+        if let Some(format) = attributes.format {
+            list.push(quote!((.#member, #format, #value)));
+
+            continue;
+        }
+
+        let rename = match (shape, attributes.rename) {
+            (Shape::Struct, Some(rename)) => quote!(as #rename),
+            (Shape::Tuple, Some(rename)) => {
+                return Err(Error::new(rename.span(), "fields printed as a tuple have no names"));
+            },
+            // bindings of tuple fields are named after their indexes
+            (Shape::Struct, None) => match (access, &member) {
+                (Access::Binding, Member::Unnamed(index)) => {
+                    let name = LitStr::new(&index.index.to_string(), index.span);
+
+                    quote!(as #name)
+                },
+                _ => TokenStream2::new(),
+            },
+            (Shape::Tuple, None) => TokenStream2::new(),
+        };
+
+        let modifier = match attributes.modifier {
+            Some((modifier, args)) => quote!(: #modifier #args),
+            None => TokenStream2::new(),
+        };
+
+        list.push(quote!(.#field #rename #modifier));
+    }
+
+    for (name, value) in ghosts {
+        list.push(quote!(let .#name = #value));
+    }
+
+    Ok(list)
+}
+
+fn derive_struct(ast: &DeriveInput, fields: &Fields) -> Result<TokenStream2, Error> {
+    let attributes = parse_item_attributes(&ast.attrs)?;
+
+    if let Some(enum_name) = attributes.enum_name.as_ref() {
+        return Err(Error::new_spanned(enum_name, "enum names can only be set on enums"));
+    }
+
+    let shape = attributes.shape.unwrap_or_else(|| Shape::of(fields));
+
+    let name = match attributes.rename {
+        Some(rename) => rename.into_token_stream(),
+        None => ast.ident.to_token_stream(),
+    };
+
+    let list = field_list(fields, shape, Access::SelfField, &attributes.ghosts)?;
+
+    let kind = match shape {
+        Shape::Struct => quote!(derive_debug_for_struct),
+        Shape::Tuple => quote!(derive_debug_for_tuple_struct),
+    };
+
+    Ok(impl_block(ast, kind, quote!(#name, self #(, #list)*)))
+}
+
+fn derive_variant(variant: &syn::Variant) -> Result<TokenStream2, Error> {
+    let attributes = parse_item_attributes(&variant.attrs)?;
+
+    if let Some(enum_name) = attributes.enum_name.as_ref() {
+        return Err(Error::new_spanned(enum_name, "enum names can only be set on enums"));
+    }
+
+    let ident = &variant.ident;
+
+    let rename = match attributes.rename {
+        Some(rename) => quote!(as #rename),
+        None => TokenStream2::new(),
+    };
+
+    if matches!(variant.fields, Fields::Unit) && attributes.ghosts.is_empty() {
+        return Ok(quote!(#ident #rename));
+    }
+
+    let shape = attributes.shape.unwrap_or_else(|| Shape::of(&variant.fields));
+
+    let mut bindings = Vec::new();
+
+    for (i, field) in variant.fields.iter().enumerate() {
+        let skip = parse_field_attributes(&field.attrs)?.skip;
+
+        bindings.push(match field.ident.as_ref() {
+            Some(ident) if skip => quote!(#ident: _),
+            Some(ident) => quote!(#ident),
+            None if skip => quote!(_),
+            None => binding(&Member::Unnamed(Index::from(i))).into_token_stream(),
+        });
+    }
+
+    let pattern = match variant.fields {
+        Fields::Unnamed(_) => quote!((#(#bindings),*)),
+        _ => quote!({#(#bindings),*}),
+    };
+
+    let list = field_list(&variant.fields, shape, Access::Binding, &attributes.ghosts)?;
+
+    let fields = if list.is_empty() { TokenStream2::new() } else { quote!(: (#(#list),*)) };
+
+    Ok(match shape {
+        Shape::Struct => quote!({#ident #pattern #rename #fields}),
+        Shape::Tuple => quote!((#ident #pattern #rename #fields)),
+    })
+}
+
+fn derive_enum(ast: &DeriveInput, data: &DataEnum) -> Result<TokenStream2, Error> {
+    let attributes = parse_item_attributes(&ast.attrs)?;
+
+    if let Some(rename) = attributes.rename.as_ref() {
+        return Err(Error::new(rename.span(), "enums cannot be renamed, rename their variants"));
+    }
+
+    if let Some((name, _)) = attributes.ghosts.first() {
+        return Err(Error::new(name.span(), "ghost fields of enums belong to their variants"));
+    }
+
+    let name = &ast.ident;
+
+    if data.variants.is_empty() {
+        let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+        return Ok(quote! {
+            impl #impl_generics ::core::fmt::Debug for #name #ty_generics #where_clause {
+                #[inline]
+                fn fmt(&self, _: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match *self {}
+                }
+            }
+        });
+    }
+
+    let variants = data.variants.iter().map(derive_variant).collect::<Result<Vec<_>, Error>>()?;
+
+    let mode = attributes.enum_name.map(|mode| quote!(as #mode));
+
+    let body = quote!(#name::{#(#variants),*} #mode, self);
+
+    Ok(impl_block(ast, quote!(derive_debug_for_enum), body))
+}
+
+/// Expands to the `impl` form of a `derive_debug_for_*` macro. Like `#[derive(Debug)]`, every type parameter must implement `Debug`.
+fn impl_block(ast: &DeriveInput, kind: TokenStream2, body: TokenStream2) -> TokenStream2 {
+    let ident = &ast.ident;
+    let mut generics = ast.generics.clone();
+
+    let type_params: Vec<Ident> = generics.type_params().map(|param| param.ident.clone()).collect();
+
+    for param in type_params {
+        generics.make_where_clause().predicates.push(parse_quote!(#param: ::core::fmt::Debug));
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        ::debug_helper::#kind! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #body
+            }
+        }
+    }
+}
+
+fn derive(ast: &DeriveInput) -> Result<TokenStream2, Error> {
+    match &ast.data {
+        Data::Struct(data) => derive_struct(ast, &data.fields),
+        Data::Enum(data) => derive_enum(ast, data),
+        Data::Union(data) => Err(Error::new(
+            data.union_token.span,
+            "`DebugHelper` cannot be derived for unions, use `impl_debug_for_union!`",
+        )),
+    }
+}
+
+#[proc_macro_derive(DebugHelper, attributes(debug_helper))]
+pub fn debug_helper_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    match derive(&ast) {
+        Ok(token_stream) => token_stream.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
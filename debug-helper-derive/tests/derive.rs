@@ -0,0 +1,319 @@
+use std::fmt::Debug;
+
+use debug_helper::DebugHelper;
+
+#[test]
+fn unit_structure() {
+    #[derive(DebugHelper)]
+    struct Unit;
+
+    #[derive(DebugHelper)]
+    struct Empty {}
+
+    #[derive(DebugHelper)]
+    enum Never {}
+
+    fn assert_debug<T: Debug>() {}
+
+    assert_debug::<Never>();
+
+    assert_eq!("Unit", format!("{:?}", Unit));
+    assert_eq!("Unit", format!("{:#?}", Unit));
+    assert_eq!("Empty", format!("{:?}", Empty {}));
+}
+
+#[test]
+fn structure() {
+    #[derive(DebugHelper)]
+    struct Inner {
+        f1: u8,
+        f2: &'static str,
+    }
+
+    #[derive(DebugHelper)]
+    #[allow(dead_code)]
+    struct Outer {
+        #[debug_helper(rename = "inner")]
+        f1:  Inner,
+        #[debug_helper(format = "{:.3}")]
+        f2:  f64,
+        #[debug_helper(skip)]
+        f3:  i32,
+        #[debug_helper(hex(4))]
+        f4:  u16,
+        #[debug_helper(redacted)]
+        key: &'static str,
+    }
+
+    let outer = Outer {
+        f1:  Inner {
+            f1: 1, f2: "Hi"
+        },
+        f2:  1.23456,
+        f3:  3,
+        f4:  0xAB,
+        key: "password",
+    };
+
+    assert_eq!(
+        "Outer { inner: Inner { f1: 1, f2: \"Hi\" }, f2: 1.235, f4: 0x00ab, key: <redacted> }",
+        format!("{:?}", outer)
+    );
+    assert_eq!(
+        "Outer {\n    inner: Inner {\n        f1: 1,\n        f2: \"Hi\",\n    },\n    f2: \
+         1.235,\n    f4: 0x00ab,\n    key: <redacted>,\n}",
+        format!("{:#?}", outer)
+    );
+}
+
+#[test]
+fn tuple_structure() {
+    #[derive(DebugHelper)]
+    #[allow(dead_code)]
+    struct Outer(u8, #[debug_helper(skip)] u8, #[debug_helper(format = "{:02}")] u8);
+
+    assert_eq!("Outer(1, 03)", format!("{:?}", Outer(1, 2, 3)));
+    assert_eq!("Outer(\n    1,\n    03,\n)", format!("{:#?}", Outer(1, 2, 3)));
+}
+
+#[test]
+fn rename_and_ghost() {
+    #[derive(DebugHelper)]
+    #[debug_helper(rename = "Point", ghost(sum = self.x + self.y))]
+    struct P {
+        x: i32,
+        y: i32,
+    }
+
+    assert_eq!(
+        "Point { x: 1, y: 2, sum: 3 }",
+        format!("{:?}", P {
+            x: 1, y: 2
+        })
+    );
+}
+
+#[test]
+fn shape() {
+    #[derive(DebugHelper)]
+    #[debug_helper(shape = "tuple")]
+    struct Named {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(DebugHelper)]
+    #[debug_helper(shape = "struct")]
+    struct Tuple(i32, #[debug_helper(rename = "y")] i32);
+
+    assert_eq!(
+        "Named(1, 2)",
+        format!("{:?}", Named {
+            x: 1, y: 2
+        })
+    );
+    assert_eq!("Tuple { 0: 1, y: 2 }", format!("{:?}", Tuple(1, 2)));
+}
+
+#[test]
+fn generic() {
+    #[derive(DebugHelper)]
+    struct Outer<'a, T, const N: usize> {
+        f1: &'a T,
+        f2: [u8; N],
+    }
+
+    assert_eq!(
+        "Outer { f1: \"Hi\", f2: [1, 2] }",
+        format!("{:?}", Outer {
+            f1: &"Hi", f2: [1, 2]
+        })
+    );
+}
+
+#[test]
+fn enumeration() {
+    #[derive(DebugHelper)]
+    #[allow(dead_code)]
+    enum Outer {
+        V1,
+        #[debug_helper(rename = "Two")]
+        V2,
+        V3(u8, #[debug_helper(skip)] u8, #[debug_helper(format = "{:.1}")] f64),
+        V4 {
+            f1: u8,
+            #[debug_helper(rename = "second")]
+            f2: u8,
+            #[debug_helper(skip)]
+            f3: u8,
+        },
+        #[debug_helper(shape = "struct")]
+        V5(u8, #[debug_helper(format = "{:02}")] u8),
+        #[debug_helper(ghost(ok = true))]
+        V6,
+    }
+
+    assert_eq!("V1", format!("{:?}", Outer::V1));
+    assert_eq!("Two", format!("{:?}", Outer::V2));
+    assert_eq!("V3(1, 3.0)", format!("{:?}", Outer::V3(1, 2, 3.0)));
+    assert_eq!(
+        "V4 { f1: 1, second: 2 }",
+        format!("{:?}", Outer::V4 {
+            f1: 1, f2: 2, f3: 3
+        })
+    );
+    assert_eq!("V5 { 0: 1, 1: 02 }", format!("{:?}", Outer::V5(1, 2)));
+    assert_eq!("V6 { ok: true }", format!("{:?}", Outer::V6));
+    assert_eq!(
+        "V4 {\n    f1: 1,\n    second: 2,\n}",
+        format!("{:#?}", Outer::V4 {
+            f1: 1, f2: 2, f3: 3
+        })
+    );
+}
+
+#[test]
+fn enumeration_with_name() {
+    #[derive(DebugHelper)]
+    #[debug_helper(enum_name)]
+    enum Outer<T> {
+        V1,
+        V2(T),
+        V3 { f1: T },
+    }
+
+    assert_eq!("Outer::V1", format!("{:?}", Outer::<u8>::V1));
+    assert_eq!("Outer::V2(1)", format!("{:?}", Outer::V2(1)));
+    assert_eq!(
+        "Outer::V3 { f1: 1 }",
+        format!("{:?}", Outer::V3 {
+            f1: 1
+        })
+    );
+    #[derive(DebugHelper)]
+    #[debug_helper(enum_name = "geo::Shape")]
+    enum Shape {
+        Point,
+    }
+
+    #[derive(DebugHelper)]
+    #[debug_helper(enum_path)]
+    enum Path {
+        Point,
+    }
+
+    assert_eq!("geo::Shape::Point", format!("{:?}", Shape::Point));
+    assert_eq!("derive::Path::Point", format!("{:?}", Path::Point));
+}
+
+#[test]
+fn same_as_declarative_macros() {
+    #[derive(DebugHelper)]
+    #[allow(dead_code)]
+    enum Derived {
+        V1,
+        V2(u8, #[debug_helper(format = "{:.2}")] f64),
+        V3 {
+            #[debug_helper(upper_hex)]
+            f1: u8,
+            #[debug_helper(skip)]
+            f2: u8,
+        },
+    }
+
+    #[allow(dead_code)]
+    enum Declared {
+        V1,
+        V2(u8, f64),
+        V3 { f1: u8, f2: u8 },
+    }
+
+    debug_helper::derive_debug_for_enum!(Declared::{V1, (V2(_0, _1): (._0, (._1, "{:.2}", _1))), {V3{f1, f2: _}: (.f1: upper_hex)}}, self);
+
+    for (derived, declared) in [
+        (Derived::V1, Declared::V1),
+        (Derived::V2(1, 2.0), Declared::V2(1, 2.0)),
+        (
+            Derived::V3 {
+                f1: 255, f2: 0
+            },
+            Declared::V3 {
+                f1: 255, f2: 0
+            },
+        ),
+    ] {
+        assert_eq!(format!("{:?}", declared), format!("{:?}", derived));
+        assert_eq!(format!("{:#?}", declared), format!("{:#?}", derived));
+    }
+}
+
+#[test]
+fn same_as_std() {
+    mod std_derive {
+        #![allow(dead_code)]
+
+        #[derive(Debug)]
+        pub struct S<T> {
+            pub a: T,
+            pub b: Option<(u8, &'static str)>,
+        }
+
+        #[derive(Debug)]
+        pub struct T(pub u8, pub [i8; 2]);
+
+        #[derive(Debug)]
+        pub enum E {
+            Unit,
+            EmptyTuple(),
+            EmptyStruct {},
+            Tuple(f64, u8),
+            Struct { f1: i8 },
+        }
+    }
+
+    #[derive(DebugHelper)]
+    struct S<T> {
+        a: T,
+        b: Option<(u8, &'static str)>,
+    }
+
+    #[derive(DebugHelper)]
+    struct T(u8, [i8; 2]);
+
+    #[derive(DebugHelper)]
+    enum E {
+        Unit,
+        EmptyTuple(),
+        EmptyStruct {},
+        Tuple(f64, u8),
+        Struct { f1: i8 },
+    }
+
+    fn assert_same(expected: impl Debug, actual: impl Debug) {
+        assert_eq!(format!("{:?}", expected), format!("{:?}", actual));
+        assert_eq!(format!("{:#?}", expected), format!("{:#?}", actual));
+        assert_eq!(format!("{:5?}", expected), format!("{:5?}", actual));
+    }
+
+    assert_same(
+        std_derive::S {
+            a: 1.5, b: Some((1, "Hi"))
+        },
+        S {
+            a: 1.5, b: Some((1, "Hi"))
+        },
+    );
+    assert_same(std_derive::T(1, [-1, 2]), T(1, [-1, 2]));
+    assert_same(std_derive::E::Unit, E::Unit);
+    assert_same(std_derive::E::EmptyTuple(), E::EmptyTuple());
+    assert_same(std_derive::E::EmptyStruct {}, E::EmptyStruct {});
+    assert_same(std_derive::E::Tuple(1.5, 2), E::Tuple(1.5, 2));
+    assert_same(
+        std_derive::E::Struct {
+            f1: -1
+        },
+        E::Struct {
+            f1: -1
+        },
+    );
+}
@@ -0,0 +1,33 @@
+use std::fmt::{self, Debug, Formatter};
+
+pub enum A {
+    V1(u8, i16),
+    V2 { f1: u8, f2: i16 },
+}
+
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_enum!(A::{{V1(f1, f2): (let .left = f1, let .right = f2)}, (V2{f1, f2}: (.f1, .f2))}, f, self);
+    }
+}
+
+fn main() {
+    let a = A::V1(1, 2);
+    let b = A::V2 {
+        f1: 1, f2: 2
+    };
+
+    println!("{:#?}", a);
+    println!("{:#?}", b);
+
+    /*
+        V1 {
+            left: 1,
+            right: 2,
+        }
+        V2(
+            1,
+            2,
+        )
+    */
+}
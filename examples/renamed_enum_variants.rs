@@ -0,0 +1,35 @@
+use std::fmt::{self, Debug, Formatter};
+
+pub enum A {
+    Ready,
+    Running(u8),
+    Done { code: i32 },
+}
+
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_enum!({A::Ready as Idle, (Running(n) as "Busy": (.n)), {Done{code} as Finished: (.code)}}, f, self);
+    }
+}
+
+fn main() {
+    let a = A::Ready;
+    let b = A::Running(1);
+    let c = A::Done {
+        code: 0
+    };
+
+    println!("{:#?}", a);
+    println!("{:#?}", b);
+    println!("{:#?}", c);
+
+    /*
+        A::Idle
+        A::Busy(
+            1,
+        )
+        A::Finished {
+            code: 0,
+        }
+    */
+}
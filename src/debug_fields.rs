@@ -0,0 +1,16 @@
+use core::fmt::DebugStruct;
+
+/// Types whose fields can be written into the `DebugStruct` builder of another type, like the fields of a flattened struct.
+///
+/// Implement it with `derive_debug_fields_for_struct!` and use it with the `.field: flatten` field form.
+pub trait DebugFields {
+    /// Writes the fields into the builder.
+    fn fmt_fields(&self, builder: &mut DebugStruct<'_, '_>);
+}
+
+impl<T: DebugFields + ?Sized> DebugFields for &T {
+    #[inline]
+    fn fmt_fields(&self, builder: &mut DebugStruct<'_, '_>) {
+        (**self).fmt_fields(builder)
+    }
+}
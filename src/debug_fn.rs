@@ -0,0 +1,51 @@
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// A closure which is called to write the `Debug` and `Display` output, like `fmt::from_fn`.
+///
+/// ```rust
+/// use debug_helper::DebugFn;
+///
+/// let items = [1, 2, 3];
+///
+/// let sum = DebugFn(|f| {
+///     let mut first = true;
+///
+///     for item in items.iter() {
+///         if !first {
+///             f.write_str(" + ")?;
+///         }
+///
+///         first = false;
+///
+///         write!(f, "{}", item)?;
+///     }
+///
+///     Ok(())
+/// });
+///
+/// assert_eq!("1 + 2 + 3", format!("{:?}", sum));
+/// ```
+#[derive(Clone, Copy)]
+pub struct DebugFn<F: Fn(&mut Formatter<'_>) -> fmt::Result>(pub F);
+
+impl<F: Fn(&mut Formatter<'_>) -> fmt::Result> DebugFn<F> {
+    /// Wraps a closure.
+    #[inline]
+    pub fn new(f: F) -> DebugFn<F> {
+        DebugFn(f)
+    }
+}
+
+impl<F: Fn(&mut Formatter<'_>) -> fmt::Result> Debug for DebugFn<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+impl<F: Fn(&mut Formatter<'_>) -> fmt::Result> Display for DebugFn<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
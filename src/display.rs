@@ -0,0 +1,56 @@
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// A value whose `Debug` output is its `Display` output, for example a URL or an IP address without quotes.
+///
+/// ```rust
+/// use std::net::Ipv4Addr;
+///
+/// use debug_helper::DisplayAsDebug;
+///
+/// assert_eq!("Hello", format!("{:?}", DisplayAsDebug("Hello")));
+/// assert_eq!(
+///     "127.0.0.1",
+///     format!("{:?}", DisplayAsDebug(Ipv4Addr::LOCALHOST))
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct DisplayAsDebug<T>(pub T);
+
+impl<T: Display> Debug for DisplayAsDebug<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<T: Display> Display for DisplayAsDebug<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+/// A value whose `Display` output is its compact `Debug` output.
+///
+/// ```rust
+/// use debug_helper::DebugAsDisplay;
+///
+/// assert_eq!("Some(\"Hi\")", format!("{}", DebugAsDisplay(Some("Hi"))));
+/// assert_eq!("Some(\"Hi\")", format!("{:#}", DebugAsDisplay(Some("Hi"))));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct DebugAsDisplay<T>(pub T);
+
+impl<T: Debug> Display for DebugAsDisplay<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("{:?}", self.0))
+    }
+}
+
+impl<T: Debug> Debug for DebugAsDisplay<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
@@ -0,0 +1,84 @@
+use core::fmt::{self, Debug, Formatter, Write};
+
+/// Bytes which are printed as a compact hex string with `{:?}`, and as an `xxd`-style dump with `{:#?}`.
+///
+/// ```rust
+/// use debug_helper::HexDump;
+///
+/// let dump = HexDump::new(b"GET / HTTP/1.1\r\nHost");
+///
+/// assert_eq!(
+///     "[474554202f20485454502f312e310d0a486f7374]",
+///     format!("{:?}", dump)
+/// );
+///
+/// assert_eq!(
+///     "[
+///     00000000: 4745 5420 2f20 4854 5450 2f31 2e31 0d0a  GET / HTTP/1.1..
+///     00000010: 486f 7374                                Host
+/// ]",
+///     format!("{:#?}", dump)
+/// );
+/// ```
+#[derive(Clone, Copy)]
+pub struct HexDump<B> {
+    b: B,
+}
+
+impl<B> HexDump<B> {
+    /// Wraps bytes.
+    #[inline]
+    pub const fn new(b: B) -> HexDump<B> {
+        HexDump {
+            b,
+        }
+    }
+
+    /// Unwraps the bytes.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.b
+    }
+}
+
+impl<B: AsRef<[u8]>> Debug for HexDump<B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let b = self.b.as_ref();
+
+        f.write_char('[')?;
+
+        if f.alternate() {
+            // every line is written with its own indentation, so the formatter can indent it further when this dump is nested
+            for (i, line) in b.chunks(16).enumerate() {
+                f.write_fmt(format_args!("\n    {:08x}:", i * 16))?;
+
+                for j in 0..16 {
+                    if j % 2 == 0 {
+                        f.write_char(' ')?;
+                    }
+
+                    match line.get(j) {
+                        Some(c) => f.write_fmt(format_args!("{:02x}", c))?,
+                        None => f.write_str("  ")?,
+                    }
+                }
+
+                f.write_str("  ")?;
+
+                for &c in line {
+                    f.write_char(if (0x20..0x7F).contains(&c) { c as char } else { '.' })?;
+                }
+            }
+
+            if !b.is_empty() {
+                f.write_char('\n')?;
+            }
+        } else {
+            for c in b {
+                f.write_fmt(format_args!("{:02x}", c))?;
+            }
+        }
+
+        f.write_char(']')
+    }
+}
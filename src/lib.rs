@@ -111,7 +111,7 @@ println!("{:#?}", c);
 */
 ```
 
-For enums (with the enum name),
+For enums (with the enum name), add `as name` after the variants,
 
 ```rust
 use std::fmt::{self, Formatter, Debug};
@@ -128,7 +128,7 @@ pub enum A {
 
 impl Debug for A {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
-        debug_helper::impl_debug_for_enum!({A::V1, (V2(f1, _, f3): (.f1, (.f3, "{:.3}", f3))), {V3{f1, f2: _, f3}: (.f1, (.f3, "{:.3}", f3))}}, f, self);
+        debug_helper::impl_debug_for_enum!(A::{V1, (V2(f1, _, f3): (.f1, (.f3, "{:.3}", f3))), {V3{f1, f2: _, f3}: (.f1, (.f3, "{:.3}", f3))}} as name, f, self);
     }
 }
 
@@ -157,6 +157,76 @@ println!("{:#?}", c);
 */
 ```
 
+Enum names can also be printed with `as path`, which prefixes the path of the module where the macro is used, with `as "prefix"`, or with `as Self` for the concrete type. The `{A::V1, ...}` form is the same as `A::{V1, ...} as name`,
+
+```rust
+mod shapes {
+    use std::fmt::{self, Formatter, Debug};
+
+    pub enum Shape {
+        Point,
+        Circle(u8),
+    }
+
+    impl Debug for Shape {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            if f.alternate() {
+                debug_helper::impl_debug_for_enum!(Shape::{Point, (Circle(r): (.r))} as "geo::Shape", f, self);
+            } else {
+                debug_helper::impl_debug_for_enum!(Shape::{Point, (Circle(r): (.r))} as path, f, self);
+            }
+        }
+    }
+}
+
+let a = shapes::Shape::Point;
+let b = shapes::Shape::Circle(3);
+
+println!("{:?}", a);
+println!("{:#?}", b);
+
+/*
+    my_crate::shapes::Shape::Point
+    geo::Shape::Circle(
+        3,
+    )
+*/
+```
+
+`as std` writes the same output as `#[derive(Debug)]`. Variants without a field list write the fields bound by their patterns, and fields bound to `_` or left out by `..` are skipped. `ref` bindings are accepted, `mut` and `ref mut` bindings are not,
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+
+pub enum A {
+    V1,
+    V2(),
+    V3(u8, i16),
+    V4 {
+        f1: u8,
+        f2: i16,
+    },
+}
+
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_enum!(A::{V1, (V2()), (V3(f1, f2)), {V4{f1, f2}}} as std, f, self);
+    }
+}
+
+println!("{:?}", A::V1);
+println!("{:?}", A::V2());
+println!("{:?}", A::V3(1, 2));
+println!("{:?}", A::V4 { f1: 1, f2: 2 });
+
+/*
+    V1
+    V2
+    V3(1, 2)
+    V4 { f1: 1, f2: 2 }
+*/
+```
+
 
 
 Ghost fields,
@@ -277,223 +347,2093 @@ println!("{:#?}", a);
 */
 ```
 
-## TODO
+Fake enum variants,
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+
+pub enum A {
+    V1(u8, i16),
+    V2 {
+        f1: u8,
+        f2: i16,
+    },
+}
+
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_enum!(A::{{V1(f1, f2): (let .left = f1, let .right = f2)}, (V2{f1, f2}: (.f1, .f2))}, f, self);
+    }
+}
+
+let a = A::V1(1, 2);
+let b = A::V2{
+    f1: 1,
+    f2: 2,
+};
 
-1. Fake enum struct variants and tuple variants.
-1. Enum variants can be renamed.
+println!("{:#?}", a);
+println!("{:#?}", b);
 
+/*
+    V1 {
+        left: 1,
+        right: 2,
+    }
+    V2(
+        1,
+        2,
+    )
 */
+```
 
-#![no_std]
+Renamed enum variants,
 
-extern crate alloc;
+```rust
+use std::fmt::{self, Formatter, Debug};
 
-#[doc(hidden)]
-pub mod __private {
-    pub use alloc::format;
-    use alloc::{
-        fmt::{Debug, Formatter, Result as FormatResult},
-        string::String,
-    };
+pub enum A {
+    Ready,
+    Running(u8),
+    Done {
+        code: i32,
+    },
+}
 
-    pub struct RawString(pub String);
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_enum!(A::{Ready as Idle, (Running(n) as "Busy": (.n)), {Done{code} as Finished: (.code)}} as name, f, self);
+    }
+}
 
-    impl Debug for RawString {
-        fn fmt(&self, f: &mut Formatter<'_>) -> FormatResult {
-            if f.alternate() {
-                f.write_str(self.0.replace('\n', "\n    ").as_str())
-            } else {
-                f.write_str(self.0.as_str())
-            }
-        }
+let a = A::Ready;
+let b = A::Running(1);
+let c = A::Done{
+    code: 0,
+};
+
+println!("{:#?}", a);
+println!("{:#?}", b);
+println!("{:#?}", c);
+
+/*
+    A::Idle
+    A::Busy(
+        1,
+    )
+    A::Finished {
+        code: 0,
     }
+*/
+```
+
+Renamed structs,
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+
+pub struct Handle {
+    pub fd: i32,
 }
 
-#[doc(hidden)]
-pub use __private::RawString;
+pub struct Socket(pub u16);
 
-#[macro_export]
-macro_rules! impl_debug_for_struct {
-    // TODO unit struct
-    ($struct_name:ident, $formatter:expr $(, $self:expr)? $(,)*) => {
-        return $formatter.write_str(stringify!($struct_name));
-    };
-    // TODO struct
-    ($struct_name:ident, $formatter:expr, $self:expr, $( $(.$field:ident)? $((.$field_2:ident, $($field_2_fmt:tt)+))? $(let .$field_3:ident = $field_3_value:expr)? ),* $(,)*) => {
-        {
-            let mut builder = $formatter.debug_struct(stringify!($struct_name));
+impl Debug for Handle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!("FileHandle", f, self, .fd);
+    }
+}
 
-            $(
-                $(
-                    builder.field(stringify!($field), &$self.$field);
-                )?
+impl Debug for Socket {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_tuple_struct!(net::Socket, f, self, .0);
+    }
+}
 
-                $(
-                    builder.field(stringify!($field_2), &$crate::__private::RawString($crate::__private::format!($($field_2_fmt)*)));
-                )?
+let a = Handle {
+    fd: 3,
+};
+let b = Socket(8080);
 
-                $(
-                    builder.field(stringify!($field_3), &$field_3_value);
-                )?
-            )*
+println!("{:#?}", a);
+println!("{:#?}", b);
 
-            return builder.finish();
-        }
-    };
+/*
+    FileHandle {
+        fd: 3,
+    }
+    net::Socket(
+        8080,
+    )
+*/
+```
+
+Renamed fields,
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+
+pub struct A {
+    pub inner_buf: Vec<u8>,
+    pub len: usize,
 }
 
-#[macro_export]
-macro_rules! impl_debug_for_tuple_struct {
-    // TODO unit tuple struct
-    ($struct_name:ident, $formatter:expr $(, $self:expr)? $(,)*) => {
-        return $formatter.write_str(stringify!($struct_name));
-    };
-    // TODO tuple struct
-    ($struct_name:ident, $formatter:expr, $self:expr, $( $(.$field:tt)? $((.$field_2:tt, $($field_2_fmt:tt)+))? $(let .$field_3:tt = $field_3_value:expr)? ),* $(,)*) => {
-        {
-            let mut builder = $formatter.debug_tuple(stringify!($struct_name));
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(A, f, self, .inner_buf as buffer, .len);
+    }
+}
 
-            $(
-                $(
-                    builder.field(&$self.$field);
-                )?
+let a = A {
+    inner_buf: vec![1, 2],
+    len: 2,
+};
 
-                $(
-                    builder.field(&$crate::__private::RawString($crate::__private::format!($($field_2_fmt)*)));
-                )?
+println!("{:#?}", a);
 
-                $(
-                    builder.field(&$field_3_value);
-                )?
-            )*
+/*
+    A {
+        buffer: [
+            1,
+            2,
+        ],
+        len: 2,
+    }
+*/
+```
 
-            return builder.finish();
-        }
+The `impl_debug_for_*` macros `return` from the enclosing function. Use `fmt_struct!`, `fmt_tuple_struct!` and `fmt_enum!` to get an expression of type `fmt::Result` instead,
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+
+pub struct A {
+    pub f1: u8,
+    pub f2: i16,
+}
+
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::fmt_struct!(A, f, self, .f1, .f2)?;
+
+        f.write_str(" (checked)")
     }
 }
 
-#[macro_export]
-macro_rules! impl_debug_for_enum {
-    // TODO enum
-    ($enum_name:ident::{$( $($variant_unit:ident)? $(($variant_tuple:ident ($($tuple:tt)*) $(:($( $(.$t_field:tt)? $((.$t_field_2:tt, $($t_field_2_fmt:tt)+))? $(let .$t_field_3:tt = $t_field_3_value:expr)? ),* $(,)*))? ) )? $({$variant_struct:ident {$($struct:tt)*} $(:($( $(.$s_field:tt)? $((.$s_field_2:tt, $($s_field_2_fmt:tt)+))? $(let .$s_field_3:ident = $s_field_3_value:expr)? ),* $(,)*))? })? ),+ $(,)*}, $formatter:expr, $self:expr $(,)*) => {
-        {
-            match $self {
-                $(
-                    $(
-                        Self::$variant_unit => {
-                            return $formatter.write_str(stringify!($variant_unit));
-                        }
-                    )?
-                    $(
-                        Self::$variant_tuple ($($tuple)*)=> {
-                            let mut builder = $formatter.debug_tuple(stringify!($variant_tuple));
-
-                            $(
-                                $(
-                                    $(
-                                        builder.field(&$t_field);
-                                    )?
-
-                                    $(
-                                        builder.field(&$crate::__private::RawString($crate::__private::format!($($t_field_2_fmt)*)));
-                                    )?
-
-                                    $(
-                                        builder.field(&$t_field_3_value);
-                                    )?
-                                )*
-                            )?
-
-                            return builder.finish();
-                        }
-                    )?
-                    $(
-                        Self::$variant_struct {$($struct)*}=> {
-                            let mut builder = $formatter.debug_struct(stringify!($variant_struct));
-
-                            $(
-                                $(
-                                    $(
-                                        builder.field(stringify!($s_field), &$s_field);
-                                    )?
-
-                                    $(
-                                        builder.field(stringify!($s_field_2), &$crate::__private::RawString($crate::__private::format!($($s_field_2_fmt)*)));
-                                    )?
-
-                                    $(
-                                        builder.field(stringify!($s_field_3), &$s_field_3_value);
-                                    )?
-                                )*
-                            )?
-
-                            return builder.finish();
-                        }
-                    )?
-                )+
-            }
-        }
-    };
-    // TODO enum full path
-    ({$enum_name:ident::$( $($variant_unit:ident)? $(($variant_tuple:ident ($($tuple:tt)*) $(:($( $(.$t_field:tt)? $((.$t_field_2:tt, $($t_field_2_fmt:tt)+))? $(let .$t_field_3:tt = $t_field_3_value:expr)? ),* $(,)*))? ) )? $({$variant_struct:ident {$($struct:tt)*} $(:($( $(.$s_field:tt)? $((.$s_field_2:tt, $($s_field_2_fmt:tt)+))? $(let .$s_field_3:ident = $s_field_3_value:expr)? ),* $(,)*))? })? ),+ $(,)*}, $formatter:expr, $self:expr $(,)*) => {
-        {
-            match $self {
-                $(
-                    $(
-                        Self::$variant_unit => {
-                            $formatter.write_str(stringify!($enum_name))?;
-                            $formatter.write_str("::")?;
-                            return $formatter.write_str(stringify!($variant_unit));
-                        }
-                    )?
-                    $(
-                        Self::$variant_tuple ($($tuple)*)=> {
-                            let mut builder = $formatter.debug_tuple(concat!(stringify!($enum_name), "::", stringify!($variant_tuple)));
-
-                            $(
-                                $(
-                                    $(
-                                        builder.field(&$t_field);
-                                    )?
-
-                                    $(
-                                        builder.field(&$crate::__private::RawString($crate::__private::format!($($t_field_2_fmt)*)));
-                                    )?
-
-                                    $(
-                                        builder.field(&$t_field_3_value);
-                                    )?
-                                )*
-                            )?
-
-                            return builder.finish();
-                        }
-                    )?
-                    $(
-                        Self::$variant_struct {$($struct)*}=> {
-                            let mut builder = $formatter.debug_struct(concat!(stringify!($enum_name), "::", stringify!($variant_struct)));
-
-                            $(
-                                $(
-                                    $(
-                                        builder.field(stringify!($s_field), &$s_field);
-                                    )?
-
-                                    $(
-                                        builder.field(stringify!($s_field_2), &$crate::__private::RawString($crate::__private::format!($($s_field_2_fmt)*)));
-                                    )?
-
-                                    $(
-                                        builder.field(stringify!($s_field_3), &$s_field_3_value);
-                                    )?
-                                )*
-                            )?
-
-                            return builder.finish();
-                        }
-                    )?
-                )+
-            }
+let a = A {
+    f1: 1,
+    f2: 2,
+};
+
+println!("{:?}", a);
+
+/*
+    A { f1: 1, f2: 2 } (checked)
+*/
+```
+
+Whole `impl Debug` blocks can be generated with `derive_debug_for_struct!`, `derive_debug_for_tuple_struct!` and `derive_debug_for_enum!`. They take the same arguments as the macros above without the formatter. Use the `impl<...> Type where ... { ... }` form for generic types,
+
+```rust
+use std::fmt::Debug;
+
+pub struct A {
+    pub f1: u8,
+    pub f2: i16,
+    pub f3: f64,
+}
+
+debug_helper::derive_debug_for_struct!(A, self, .f1, (.f3, "{:.3}", self.f3));
+
+pub struct B<'a, T>(pub &'a T, pub u8);
+
+debug_helper::derive_debug_for_tuple_struct! {
+    impl<'a, T> B<'a, T> where T: Debug {
+        B, self, .0
+    }
+}
+
+let a = A {
+    f1: 1,
+    f2: 2,
+    f3: std::f64::consts::PI,
+};
+let b = B(&"Hi", 1);
+
+println!("{:#?}", a);
+println!("{:#?}", b);
+
+/*
+    A {
+        f1: 1,
+        f3: 3.142,
+    }
+    B(
+        "Hi",
+    )
+*/
+```
+
+Generic types. The generic arguments of a type name are not printed. Use `Self` (or `as Self` for enums) to print the concrete type instead,
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+
+pub struct A<'a, T> {
+    pub f1: &'a T,
+}
+
+pub enum B<T> {
+    V1(T),
+}
+
+impl<'a, T: Debug> Debug for A<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        if f.alternate() {
+            debug_helper::impl_debug_for_struct!(Self, f, self, .f1);
+        } else {
+            debug_helper::impl_debug_for_struct!(A<'a, T>, f, self, .f1);
         }
+    }
+}
+
+impl<T: Debug> Debug for B<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_enum!(B::{(V1(v): (.v))} as Self, f, self);
+    }
+}
+
+let a = A {
+    f1: &vec![1u8],
+};
+let b = B::V1(1u8);
+
+println!("{:?}", a);
+println!("{:#?}", a);
+println!("{:?}", b);
+
+/*
+    A { f1: [1] }
+    A<Vec<u8>> {
+        f1: [
+            1,
+        ],
+    }
+    B<u8>::V1(1)
+*/
+```
+
+Non-exhaustive structs and struct variants, ending with `..`. `..?` writes the `..` only if a field is left out. A struct compares the written fields with its fields, so it is checked like `skip(...)` below and the fields which are left out must be skipped. A struct variant compares the written fields, including `let .name = ...` fields, with the fields bound or left out by its pattern,
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+
+pub struct A {
+    pub f1: u8,
+    pub f2: u8,
+}
+
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(A, f, self, .f1, ..);
+    }
+}
+
+pub enum B {
+    B1 { f1: u8, f2: u8 },
+    B2 { f1: u8, f2: u8 },
+}
+
+impl Debug for B {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_enum!(B::{{B1{f1, ..}: (.f1, ..?)}, {B2{f1, f2}: (.f1, .f2, ..?)}}, f, self);
+    }
+}
+
+pub struct C {
+    pub f1: u8,
+    pub f2: u8,
+}
+
+impl Debug for C {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(C, f, self, .f1, skip(.f2), ..?);
+    }
+}
+
+let a = A {
+    f1: 1,
+    f2: 2,
+};
+
+let b = B::B1 {
+    f1: 1,
+    f2: 2,
+};
+
+let c = B::B2 {
+    f1: 1,
+    f2: 2,
+};
+
+let d = C {
+    f1: 1,
+    f2: 2,
+};
+
+println!("{:?}", a);
+println!("{:?}", b);
+println!("{:?}", c);
+println!("{:?}", d);
+
+/*
+    A { f1: 1, .. }
+    B1 { f1: 1, .. }
+    B2 { f1: 1, f2: 2 }
+    C { f1: 1, .. }
+*/
+```
+
+Redacted fields, printed as `<redacted>`, a custom placeholder, their length or their hash (the `Redacted` type does the same outside of the macros),
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+
+pub struct A {
+    pub user: &'static str,
+    pub password: String,
+    pub token: String,
+    pub key: [u8; 32],
+}
+
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(A, f, self, .user, .password: redacted, .token: redacted("***"), .key: redacted(len));
+    }
+}
+
+let a = A {
+    user: "magiclen",
+    password: String::from("password"),
+    token: String::from("token"),
+    key: [0; 32],
+};
+
+println!("{:?}", a);
+
+/*
+    A { user: "magiclen", password: <redacted>, token: ***, key: <redacted 32 bytes> }
+*/
+```
+
+Conditional fields, with `if` followed by a condition, or `unless none`, `unless empty` and `unless default`,
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+
+pub struct A {
+    pub f1: u8,
+    pub f2: Option<u8>,
+    pub f3: Vec<u8>,
+    pub f4: u32,
+}
+
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(A, f, self, .f1 if self.f1 > 1, .f2 unless none, .f3 unless empty, .f4 unless default);
+    }
+}
+
+let a = A {
+    f1: 1,
+    f2: Some(2),
+    f3: vec![],
+    f4: 0,
+};
+
+println!("{:?}", a);
+
+/*
+    A { f2: Some(2) }
+*/
+```
+
+Flattened fields, with `: flatten`, for the types that implement `DebugFields`. `derive_debug_fields_for_struct!` generates the `impl DebugFields` block and takes the same arguments as `derive_debug_for_struct!` without the struct name,
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+
+pub struct Header {
+    pub id: u32,
+    pub kind: &'static str,
+}
+
+debug_helper::derive_debug_fields_for_struct!(Header, self, .id, .kind);
+
+pub struct A {
+    pub header: Header,
+    pub body: u8,
+}
+
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(A, f, self, .header: flatten, .body);
+    }
+}
+
+let a = A {
+    header: Header {
+        id: 1,
+        kind: "text",
+    },
+    body: 2,
+};
+
+println!("{:?}", a);
+
+/*
+    A { id: 1, kind: "text", body: 2 }
+*/
+```
+
+Collections, with `impl_debug_as_list!`, `impl_debug_as_set!` and `impl_debug_as_map!` (or `fmt_list!`, `fmt_set!` and `fmt_map!`). They take an iterator expression, and optionally a projection of each entry or a format string for each entry,
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+
+pub struct Ring {
+    buf: [u8; 4],
+    head: usize,
+    len: usize,
+}
+
+impl Ring {
+    fn iter(&self) -> impl Iterator<Item = &u8> {
+        (0..self.len).map(move |i| &self.buf[(self.head + i) % self.buf.len()])
+    }
+}
+
+impl Debug for Ring {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        if f.alternate() {
+            debug_helper::impl_debug_as_map!(f, self.iter().enumerate(), |(i, v)| i => "0x{:02X}", v);
+        } else {
+            debug_helper::impl_debug_as_list!(f, self.iter());
+        }
+    }
+}
+
+let ring = Ring {
+    buf: [3, 4, 1, 2],
+    head: 2,
+    len: 3,
+};
+
+println!("{:?}", ring);
+println!("{:#?}", ring);
+
+/*
+    [1, 2, 3]
+    {
+        0: 0x01,
+        1: 0x02,
+        2: 0x03,
+    }
+*/
+```
+
+Truncated collections, with `: truncate(N)` for lists and `: truncate_map(N)` for maps (the `Truncated` and `TruncatedMap` types do the same outside of the macros),
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+
+pub struct A {
+    pub f1: Vec<u32>,
+}
+
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(A, f, self, .f1: truncate(5));
+    }
+}
+
+let a = A {
+    f1: (0..10000).collect(),
+};
+
+println!("{:?}", a);
+
+/*
+    A { f1: [0, 1, 2, 3, 4, ... 9,995 more] }
+*/
+```
+
+Truncated strings and bytes, with `: truncate_str(N)` and `: truncate_bytes(N)` (the `TruncatedStr` and `TruncatedBytes` types do the same outside of the macros). Strings are cut at a char boundary,
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+
+pub struct A {
+    pub name: String,
+    pub body: Vec<u8>,
+}
+
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(A, f, self, .name: truncate_str(5), .body: truncate_bytes(6));
+    }
+}
+
+let a = A {
+    name: String::from("Hello, world!"),
+    body: b"GET / HTTP/1.1\r\n".to_vec(),
+};
+
+println!("{:?}", a);
+
+/*
+    A { name: "Hello"... (13 bytes), body: b"GET / "... (16 bytes) }
+*/
+```
+
+Integers, and slices, arrays or `Vec`s of integers, in another radix, with `: hex`, `: upper_hex`, `: binary` or `: octal`. The optional width is the minimum number of digits,
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+
+pub struct A {
+    pub flags: u8,
+    pub id: u32,
+    pub key: [u8; 4],
+}
+
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(A, f, self, .flags: binary(8), .id: upper_hex, .key: hex(2));
+    }
+}
+
+let a = A {
+    flags: 0b101,
+    id: 0xBEEF,
+    key: [0, 1, 171, 255],
+};
+
+println!("{:?}", a);
+
+/*
+    A { flags: 0b00000101, id: 0xBEEF, key: [0x00, 0x01, 0xab, 0xff] }
+*/
+```
+
+Byte buffers as hex, with `: hex_dump` (the `HexDump` type does the same outside of the macros). They are printed as a compact hex string with `{:?}`, and as an `xxd`-style dump with `{:#?}`,
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+
+pub struct A {
+    pub payload: Vec<u8>,
+}
+
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(A, f, self, .payload: hex_dump);
+    }
+}
+
+let a = A {
+    payload: b"GET / HTTP/1.1\r\nHost".to_vec(),
+};
+
+println!("{:?}", a);
+println!("{:#?}", a);
+
+/*
+    A { payload: [474554202f20485454502f312e310d0a486f7374] }
+    A {
+        payload: [
+            00000000: 4745 5420 2f20 4854 5450 2f31 2e31 0d0a  GET / HTTP/1.1..
+            00000010: 486f 7374                                Host
+        ],
+    }
+*/
+```
+
+Fields printed with their `Display` implementations, with `: display` (the `DisplayAsDebug` type does the same outside of the macros, and the `DebugAsDisplay` type does the reverse),
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+use std::net::Ipv4Addr;
+
+pub struct A {
+    pub ip: Ipv4Addr,
+}
+
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(A, f, self, .ip: display);
+    }
+}
+
+let a = A {
+    ip: Ipv4Addr::LOCALHOST,
+};
+
+println!("{:?}", a);
+
+/*
+    A { ip: 127.0.0.1 }
+*/
+```
+
+Fields written by closures, with `let .field = |f| ...`, where `f` is the `Formatter` (the `DebugFn` type does the same outside of the macros),
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+
+pub struct A {
+    pub items: Vec<u8>,
+}
+
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(A, f, self, let .sum = |f| {
+            for (i, item) in self.items.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(" + ")?;
+                }
+
+                write!(f, "{}", item)?;
+            }
+
+            Ok(())
+        });
+    }
+}
+
+let a = A {
+    items: vec![1, 2, 3],
+};
+
+println!("{:?}", a);
+
+/*
+    A { sum: 1 + 2 + 3 }
+*/
+```
+
+List the fields that are left out in `skip(...)` to check that every field is either written or skipped. `Self` is destructured, so a field added later does not compile until it is written or skipped too. `skip()` checks a struct which skips nothing. In a checked struct, `(.name, ...)` counts as writing the field `name`, so fields which are not in the struct must be added with `let .name = ...`,
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+
+pub struct A {
+    pub f1: u8,
+    pub f2: &'static str,
+    pub f3: Vec<u8>,
+}
+
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_struct!(A, f, self, .f1, skip(.f2), .f3, let .len = self.f3.len());
+    }
+}
+
+pub struct B(pub u8, pub &'static str);
+
+impl Debug for B {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        debug_helper::impl_debug_for_tuple_struct!(B, f, self, skip(.0), .1);
+    }
+}
+
+let a = A {
+    f1: 1,
+    f2: "Hi",
+    f3: vec![2],
+};
+
+println!("{:?}", a);
+println!("{:?}", B(1, "Hi"));
+
+/*
+    A { f1: 1, f3: [2], len: 1 }
+    B("Hi")
+*/
+```
+
+Unions are written with `impl_debug_for_union!`. Only one field of a union can be read, so a `match` selects the active field from an expression, such as a sibling tag. `opaque` prints the raw bytes as hex instead. Reading a union field is unsafe, hence the `unsafe` keyword. It promises that a selected field is the active one, and that the union read by `opaque` has every byte initialized, i.e. it has no padding and was written through a field as large as the union. For example, `opaque` must not be selected for `union U { a: u8, b: u32 }` built with `U { a: 1 }`, because three of its bytes are uninitialized,
+
+```rust
+use std::fmt::{self, Formatter, Debug};
+
+pub enum Kind {
+    Int,
+    Bytes,
+    Unknown,
+}
+
+#[derive(Clone, Copy)]
+pub union Value {
+    pub int: u32,
+    pub bytes: [u8; 4],
+}
+
+pub struct A {
+    pub kind: Kind,
+    pub value: Value,
+}
+
+impl Debug for A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        let value = &self.value;
+
+        debug_helper::impl_debug_for_union!(Value, f, value, unsafe match self.kind {
+            Kind::Int => .int,
+            Kind::Bytes => .bytes: hex(2),
+            Kind::Unknown => opaque,
+        });
+    }
+}
+
+let a = A {
+    kind: Kind::Bytes,
+    value: Value {
+        bytes: [1, 2, 3, 4],
+    },
+};
+
+let b = A {
+    kind: Kind::Unknown,
+    value: a.value,
+};
+
+println!("{:?}", a);
+println!("{:?}", b);
+
+/*
+    Value { bytes: [0x01, 0x02, 0x03, 0x04] }
+    Value([01020304])
+*/
+```
+
+With the `derive` feature, `#[derive(DebugHelper)]` writes the same implementations from `#[debug_helper(...)]` attributes. Fields can be skipped, renamed, custom-formatted or given a modifier, and structs and variants can be renamed, given ghost fields and printed in another shape. `enum_name`, `enum_name = "prefix"` and `enum_path` print enum names,
+
+```rust,ignore
+use debug_helper::DebugHelper;
+
+#[derive(DebugHelper)]
+#[debug_helper(rename = "Point", ghost(sum = self.x + self.y))]
+pub struct A {
+    pub x: i32,
+    #[debug_helper(rename = "y_axis")]
+    pub y: i32,
+    #[debug_helper(skip)]
+    pub cache: Vec<i32>,
+}
+
+#[derive(DebugHelper)]
+#[debug_helper(enum_name)]
+pub enum B {
+    V1,
+    #[debug_helper(shape = "struct")]
+    V2(#[debug_helper(format = "{:.2}")] f64, #[debug_helper(hex)] u8),
+}
+
+let a = A {
+    x: 1,
+    y: 2,
+    cache: vec![],
+};
+
+println!("{:?}", a);
+println!("{:?}", B::V2(1.5, 255));
+
+/*
+    Point { x: 1, y_axis: 2, sum: 3 }
+    B::V2 { 0: 1.50, 1: 0xff }
+*/
+```
+
+Custom-formatted fields are written from `format_args!` directly, so nothing is allocated. The `alloc` feature is enabled by default. Disable the default features to use this crate on targets without an allocator,
+
+```toml
+[dependencies.debug-helper]
+version = "*"
+default-features = false
+```
+
+*/
+
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod debug_fields;
+mod debug_fn;
+mod display;
+mod hex_dump;
+mod radix;
+mod redacted;
+mod truncated;
+
+pub use debug_fields::DebugFields;
+pub use debug_fn::DebugFn;
+#[cfg(feature = "derive")]
+pub use debug_helper_derive::DebugHelper;
+pub use display::{DebugAsDisplay, DisplayAsDebug};
+pub use hex_dump::HexDump;
+pub use redacted::Redacted;
+pub use truncated::{Truncated, TruncatedBytes, TruncatedMap, TruncatedStr};
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::format;
+    #[cfg(feature = "alloc")]
+    use alloc::string::String;
+    use core::{
+        any,
+        fmt::{Arguments, Debug, Display, Error, Formatter, Result as FormatResult, Write},
+    };
+
+    pub use crate::radix::{Radix, RadixDebug};
+
+    #[cfg(feature = "alloc")]
+    pub struct RawString(pub String);
+
+    #[cfg(feature = "alloc")]
+    impl Debug for RawString {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FormatResult {
+            if f.alternate() {
+                PadAdapter::new(f).write_str(self.0.as_str())
+            } else {
+                f.write_str(self.0.as_str())
+            }
+        }
+    }
+
+    /// Pre-formatted arguments which are written as they are, without allocating.
+    pub struct RawArgs<'a>(pub Arguments<'a>);
+
+    impl<'a> Debug for RawArgs<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FormatResult {
+            if f.alternate() {
+                PadAdapter::new(f).write_fmt(self.0)
+            } else {
+                f.write_fmt(self.0)
+            }
+        }
+    }
+
+    /// A writer which indents every line but the first one by four spaces, so that multi-line values line up with the fields of the pretty-printed builders.
+    ///
+    /// Like the pad adapter of `core`, the indentation of a line is written lazily, before its first write, so a trailing newline (`\n` or `\r\n`) leaves no trailing spaces. A blank line inside the value is still indented.
+    pub struct PadAdapter<'a, 'b> {
+        f:          &'a mut Formatter<'b>,
+        on_newline: bool,
+    }
+
+    impl<'a, 'b> PadAdapter<'a, 'b> {
+        #[inline]
+        pub fn new(f: &'a mut Formatter<'b>) -> PadAdapter<'a, 'b> {
+            PadAdapter {
+                f,
+                on_newline: false,
+            }
+        }
+    }
+
+    impl<'a, 'b> Write for PadAdapter<'a, 'b> {
+        fn write_str(&mut self, s: &str) -> FormatResult {
+            for line in s.split_inclusive('\n') {
+                if self.on_newline {
+                    self.f.write_str("    ")?;
+                }
+
+                self.on_newline = line.ends_with('\n');
+
+                self.f.write_str(line)?;
+            }
+
+            Ok(())
+        }
+
+        fn write_char(&mut self, c: char) -> FormatResult {
+            if self.on_newline {
+                self.f.write_str("    ")?;
+            }
+
+            self.on_newline = c == '\n';
+
+            self.f.write_char(c)
+        }
+    }
+
+    /// Checks that every field bound by a pattern is written.
+    #[inline]
+    pub fn all_listed(bound: &[&str], listed: &[&str]) -> bool {
+        bound.iter().all(|field| listed.contains(field))
+    }
+
+    /// Compares a value with the default value of its type. It is a trait so that method calls can see through references.
+    pub trait IsDefault {
+        fn __debug_helper_is_default(&self) -> bool;
+    }
+
+    impl<T: Default + PartialEq> IsDefault for T {
+        #[inline]
+        fn __debug_helper_is_default(&self) -> bool {
+            *self == T::default()
+        }
+    }
+
+    /// Finds the collection behind references, so that `&&[T]` can be iterated like `&[T]`.
+    pub trait Iterable {
+        #[inline]
+        fn __debug_helper_iterable(&self) -> &Self {
+            self
+        }
+    }
+
+    impl<T: ?Sized> Iterable for T where for<'a> &'a T: IntoIterator {}
+
+    /// The name of a type without the module paths of its path segments.
+    pub struct TypeName(&'static str);
+
+    impl TypeName {
+        #[inline]
+        pub fn of<T: ?Sized>() -> TypeName {
+            TypeName(any::type_name::<T>())
+        }
+
+        fn for_each_piece<F: FnMut(&'static str) -> FormatResult>(&self, mut f: F) -> FormatResult {
+            let s = self.0;
+            let bytes = s.as_bytes();
+            let is_ident = |b: u8| b == b'_' || b.is_ascii_alphanumeric() || b >= 0x80;
+
+            let mut piece = |piece: &'static str| {
+                if piece.is_empty() {
+                    Ok(())
+                } else {
+                    f(piece)
+                }
+            };
+
+            let mut start = 0;
+            let mut i = 0;
+
+            while i < bytes.len() {
+                match bytes[i] {
+                    b':' if bytes.get(i + 1) == Some(&b':') => {
+                        i += 2;
+                        start = i;
+                    },
+                    b'<' => {
+                        // lifetimes are erased to `'_` in type names, so they are omitted
+                        let mut j = i + 1;
+
+                        while bytes.get(j) == Some(&b'\'') {
+                            j += 1;
+
+                            while j < bytes.len() && is_ident(bytes[j]) {
+                                j += 1;
+                            }
+
+                            while matches!(bytes.get(j), Some(b',') | Some(b' ')) {
+                                j += 1;
+                            }
+                        }
+
+                        if bytes.get(j) == Some(&b'>') {
+                            piece(&s[start..i])?;
+
+                            i = j + 1;
+                        } else {
+                            piece(&s[start..=i])?;
+
+                            i = j;
+                        }
+
+                        start = i;
+                    },
+                    b if is_ident(b) => i += 1,
+                    _ => {
+                        i += 1;
+
+                        piece(&s[start..i])?;
+
+                        start = i;
+                    },
+                }
+            }
+
+            piece(&s[start..])
+        }
+
+        /// Writes all but the last piece of the name and returns the last piece, because the `debug_*` builders of `Formatter` need a non-empty name.
+        #[inline]
+        pub fn write_prefix(&self, f: &mut Formatter<'_>) -> Result<&'static str, Error> {
+            let mut last = "";
+
+            self.for_each_piece(|piece| {
+                let result = f.write_str(last);
+
+                last = piece;
+
+                result
+            })?;
+
+            Ok(last)
+        }
+    }
+
+    impl Display for TypeName {
+        #[inline]
+        fn fmt(&self, f: &mut Formatter<'_>) -> FormatResult {
+            self.for_each_piece(|piece| f.write_str(piece))
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub use __private::RawString;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __debug_helper_name {
+    ($name:tt) => {
+        stringify!($name)
+    };
+    ($name:tt as $rename:literal) => {
+        $rename
+    };
+    ($name:tt as $rename:ident) => {
+        stringify!($rename)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __debug_helper_type_name {
+    // the end of the type name
+    ($callback:ident {$($args:tt)*} [$($name:tt)*] [] , $($rest:tt)*) => {
+        $crate::$callback! { $($args)* [$($name)*], $($rest)* }
+    };
+    ($callback:ident {$($args:tt)*} [$($name:tt)*] []) => {
+        $crate::$callback! { $($args)* [$($name)*] }
+    };
+    // generic arguments are not a part of the name
+    ($callback:ident {$($args:tt)*} [$($name:tt)*] [$($depth:tt)*] < $($rest:tt)*) => {
+        $crate::__debug_helper_type_name! { $callback {$($args)*} [$($name)*] [- $($depth)*] $($rest)* }
+    };
+    ($callback:ident {$($args:tt)*} [$($name:tt)*] [- $($depth:tt)*] > $($rest:tt)*) => {
+        $crate::__debug_helper_type_name! { $callback {$($args)*} [$($name)*] [$($depth)*] $($rest)* }
+    };
+    ($callback:ident {$($args:tt)*} [$($name:tt)*] [- - $($depth:tt)*] >> $($rest:tt)*) => {
+        $crate::__debug_helper_type_name! { $callback {$($args)*} [$($name)*] [$($depth)*] $($rest)* }
+    };
+    ($callback:ident {$($args:tt)*} [$($name:tt)*] [- $($depth:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::__debug_helper_type_name! { $callback {$($args)*} [$($name)*] [- $($depth)*] $($rest)* }
+    };
+    ($callback:ident {$($args:tt)*} [$($name:tt)*] [] $t:tt $($rest:tt)*) => {
+        $crate::__debug_helper_type_name! { $callback {$($args)*} [$($name)* $t] [] $($rest)* }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __debug_helper_builder {
+    // unit structs and unit variants
+    (@unit $formatter:expr, [Self]) => {
+        $formatter.write_fmt(format_args!("{}", $crate::__private::TypeName::of::<Self>()))
+    };
+    (@unit $formatter:expr, [] $name:expr) => {
+        $formatter.write_str($name)
+    };
+    // `debug_struct` and `debug_tuple` builders
+    ($method:ident, $formatter:expr, $builder:ident, [Self], $fields:block) => {
+        match $crate::__private::TypeName::of::<Self>().write_prefix($formatter) {
+            Ok(name) => {
+                let mut $builder = $formatter.$method(name);
+
+                $fields
+            },
+            Err(error) => Err(error),
+        }
+    };
+    ($method:ident, $formatter:expr, $builder:ident, [] $name:expr, $fields:block) => {
+        {
+            let mut $builder = $formatter.$method($name);
+
+            $fields
+        }
+    };
+    ($method:ident, $formatter:expr, $builder:ident, [Self] $name:expr, $fields:block) => {
+        match $formatter.write_fmt(format_args!("{}", $crate::__private::TypeName::of::<Self>())) {
+            Ok(()) => {
+                let mut $builder = $formatter.$method(concat!("::", $name));
+
+                $fields
+            },
+            Err(error) => Err(error),
+        }
+    };
+    ($method:ident, $formatter:expr, $builder:ident, [$($prefix:tt)+] $name:expr, $fields:block) => {
+        {
+            let mut $builder = $formatter.$method(concat!($($prefix)+, "::", $name));
+
+            $fields
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __debug_helper_fields {
+    // fields of `debug_struct` builders
+    (@struct ($builder:ident) [$($access:tt)*] $pattern:tt $(,)*) => {
+        ()
+    };
+    (@struct $builder:ident [$($access:tt)*] $pattern:tt $(,)*) => {
+        $builder.finish()
+    };
+    (@struct $builder:ident [$($access:tt)*] $pattern:tt .. $(,)*) => {
+        $builder.finish_non_exhaustive()
+    };
+    (@struct $builder:ident [$($access:tt)*] [$($non_exhaustive:tt)+] ..? $(,)*) => {
+        if $($non_exhaustive)+ {
+            $builder.finish_non_exhaustive()
+        } else {
+            $builder.finish()
+        }
+    };
+    (@struct $builder:tt [$($access:tt)*] $pattern:tt skip($($skipped:tt)*) $(, $($rest:tt)*)?) => {
+        $crate::__debug_helper_fields!(@struct $builder [$($access)*] $pattern $($($rest)*)?)
+    };
+    (@struct $builder:tt [$($access:tt)*] $pattern:tt .$field:tt $(as $field_name:tt)? $(: $modifier:ident $(($($args:tt)*))?)? unless $skip:ident $(, $($rest:tt)*)?) => {
+        $crate::__debug_helper_fields!(@struct $builder [$($access)*] $pattern .$field $(as $field_name)? $(: $modifier $(($($args)*))?)? if $crate::__debug_helper_fields!(@unless $skip, &$($access)* $field) $(, $($rest)*)?)
+    };
+    (@struct $builder:tt [$($access:tt)*] $pattern:tt (.$field:tt, $($fmt:tt)+) unless $skip:ident $(, $($rest:tt)*)?) => {
+        $crate::__debug_helper_fields!(@struct $builder [$($access)*] $pattern (.$field, $($fmt)+) if $crate::__debug_helper_fields!(@unless $skip, &$($access)* $field) $(, $($rest)*)?)
+    };
+    (@struct $builder:tt [$($access:tt)*] $pattern:tt .$field:ident : flatten $(if $condition:expr)? $(, $($rest:tt)*)?) => {
+        {
+            $crate::__debug_helper_fields!(@when [$(if $condition)?] [] {
+                $crate::DebugFields::fmt_fields(&$($access)* $field, &mut $builder);
+            });
+
+            $crate::__debug_helper_fields!(@struct $builder [$($access)*] $pattern $($($rest)*)?)
+        }
+    };
+    (@struct $builder:tt [$($access:tt)*] $pattern:tt .$field:tt $(as $field_name:tt)? $(: $modifier:ident $(($($args:tt)*))?)? $(if $condition:expr)? $(, $($rest:tt)*)?) => {
+        {
+            $crate::__debug_helper_fields!(@when [$(if $condition)?] [&$($access)* $field] {
+                $builder.field($crate::__debug_helper_name!($field $(as $field_name)?), &$crate::__debug_helper_modifier!($($modifier $(($($args)*))?)?, &$($access)* $field));
+            });
+
+            $crate::__debug_helper_fields!(@struct $builder [$($access)*] $pattern $($($rest)*)?)
+        }
+    };
+    (@struct $builder:tt [$($access:tt)*] $pattern:tt (.$field:tt, $($fmt:tt)+) $(if $condition:expr)? $(, $($rest:tt)*)?) => {
+        {
+            $crate::__debug_helper_fields!(@when [$(if $condition)?] [&$($access)* $field] {
+                $builder.field(stringify!($field), &$crate::__private::RawArgs(format_args!($($fmt)*)));
+            });
+
+            $crate::__debug_helper_fields!(@struct $builder [$($access)*] $pattern $($($rest)*)?)
+        }
+    };
+    (@struct $builder:tt [$($access:tt)*] $pattern:tt let .$field:ident = |$f:ident $(: $f_ty:ty)?| $body:expr $(, $($rest:tt)*)?) => {
+        {
+            $builder.field(stringify!($field), &$crate::__debug_helper_fields!(@fn $f $(: $f_ty)?, $body));
+
+            $crate::__debug_helper_fields!(@struct $builder [$($access)*] $pattern $($($rest)*)?)
+        }
+    };
+    (@struct $builder:tt [$($access:tt)*] $pattern:tt let .$field:ident = $value:expr $(, $($rest:tt)*)?) => {
+        {
+            $builder.field(stringify!($field), &$value);
+
+            $crate::__debug_helper_fields!(@struct $builder [$($access)*] $pattern $($($rest)*)?)
+        }
+    };
+    // fields of `debug_tuple` builders
+    (@tuple $builder:ident [$($access:tt)*] $(,)*) => {
+        $builder.finish()
+    };
+    (@tuple $builder:tt [$($access:tt)*] skip($($skipped:tt)*) $(, $($rest:tt)*)?) => {
+        $crate::__debug_helper_fields!(@tuple $builder [$($access)*] $($($rest)*)?)
+    };
+    (@tuple $builder:tt [$($access:tt)*] .$field:tt $(: $modifier:ident $(($($args:tt)*))?)? unless $skip:ident $(, $($rest:tt)*)?) => {
+        $crate::__debug_helper_fields!(@tuple $builder [$($access)*] .$field $(: $modifier $(($($args)*))?)? if $crate::__debug_helper_fields!(@unless $skip, &$($access)* $field) $(, $($rest)*)?)
+    };
+    (@tuple $builder:tt [$($access:tt)*] (.$field:tt, $($fmt:tt)+) unless $skip:ident $(, $($rest:tt)*)?) => {
+        $crate::__debug_helper_fields!(@tuple $builder [$($access)*] (.$field, $($fmt)+) if $crate::__debug_helper_fields!(@unless $skip, &$($access)* $field) $(, $($rest)*)?)
+    };
+    (@tuple $builder:tt [$($access:tt)*] .$field:tt $(: $modifier:ident $(($($args:tt)*))?)? $(if $condition:expr)? $(, $($rest:tt)*)?) => {
+        {
+            $crate::__debug_helper_fields!(@when [$(if $condition)?] [&$($access)* $field] {
+                $builder.field(&$crate::__debug_helper_modifier!($($modifier $(($($args)*))?)?, &$($access)* $field));
+            });
+
+            $crate::__debug_helper_fields!(@tuple $builder [$($access)*] $($($rest)*)?)
+        }
+    };
+    (@tuple $builder:tt [$($access:tt)*] (.$field:tt, $($fmt:tt)+) $(if $condition:expr)? $(, $($rest:tt)*)?) => {
+        {
+            $crate::__debug_helper_fields!(@when [$(if $condition)?] [&$($access)* $field] {
+                $builder.field(&$crate::__private::RawArgs(format_args!($($fmt)*)));
+            });
+
+            $crate::__debug_helper_fields!(@tuple $builder [$($access)*] $($($rest)*)?)
+        }
+    };
+    (@tuple $builder:tt [$($access:tt)*] let .$field:tt = |$f:ident $(: $f_ty:ty)?| $body:expr $(, $($rest:tt)*)?) => {
+        {
+            $builder.field(&$crate::__debug_helper_fields!(@fn $f $(: $f_ty)?, $body));
+
+            $crate::__debug_helper_fields!(@tuple $builder [$($access)*] $($($rest)*)?)
+        }
+    };
+    (@tuple $builder:tt [$($access:tt)*] let .$field:tt = $value:expr $(, $($rest:tt)*)?) => {
+        {
+            $builder.field(&$value);
+
+            $crate::__debug_helper_fields!(@tuple $builder [$($access)*] $($($rest)*)?)
+        }
+    };
+    // `skip(...)` turns on the check that every field of `Self` is either written or skipped, `(.name, ...)` writes the field `name`
+    (@check [$self:expr] [$($seen:tt)*] () $(,)*) => {};
+    (@check [$self:expr] [$($seen:tt)*] [$($skipped:tt)*] $(,)*) => {
+        let Self { $($seen: _,)* $($skipped: _,)* } = $self;
+    };
+    (@check $self:tt $seen:tt () ..? $(,)*) => {
+        $crate::__debug_helper_fields!(@check $self $seen [])
+    };
+    (@check $self:tt $seen:tt $skipped:tt .. $(?)? $(,)*) => {
+        $crate::__debug_helper_fields!(@check $self $seen $skipped)
+    };
+    (@check $self:tt $seen:tt () skip($(.$skip:tt),* $(,)?) $(, $($rest:tt)*)?) => {
+        $crate::__debug_helper_fields!(@check $self $seen [$($skip)*] $($($rest)*)?)
+    };
+    (@check $self:tt $seen:tt [$($skipped:tt)*] skip($(.$skip:tt),* $(,)?) $(, $($rest:tt)*)?) => {
+        $crate::__debug_helper_fields!(@check $self $seen [$($skipped)* $($skip)*] $($($rest)*)?)
+    };
+    (@check $self:tt [$($seen:tt)*] $skipped:tt .$field:tt $(as $field_name:tt)? $(: $modifier:ident $(($($args:tt)*))?)? unless $skip:ident $(, $($rest:tt)*)?) => {
+        $crate::__debug_helper_fields!(@check $self [$($seen)* $field] $skipped $($($rest)*)?)
+    };
+    (@check $self:tt [$($seen:tt)*] $skipped:tt (.$field:tt, $($fmt:tt)+) unless $skip:ident $(, $($rest:tt)*)?) => {
+        $crate::__debug_helper_fields!(@check $self [$($seen)* $field] $skipped $($($rest)*)?)
+    };
+    (@check $self:tt [$($seen:tt)*] $skipped:tt .$field:tt $(as $field_name:tt)? $(: $modifier:ident $(($($args:tt)*))?)? $(if $condition:expr)? $(, $($rest:tt)*)?) => {
+        $crate::__debug_helper_fields!(@check $self [$($seen)* $field] $skipped $($($rest)*)?)
+    };
+    (@check $self:tt [$($seen:tt)*] $skipped:tt (.$field:tt, $($fmt:tt)+) $(if $condition:expr)? $(, $($rest:tt)*)?) => {
+        $crate::__debug_helper_fields!(@check $self [$($seen)* $field] $skipped $($($rest)*)?)
+    };
+    (@check $self:tt $seen:tt $skipped:tt let .$field:tt = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__debug_helper_fields!(@check $self $seen $skipped $($($rest)*)?)
+    };
+    // closures
+    (@fn $f:ident, $body:expr) => {
+        $crate::DebugFn(|$f: &mut ::core::fmt::Formatter<'_>| $body)
+    };
+    (@fn $f:ident : $f_ty:ty, $body:expr) => {
+        $crate::DebugFn(|$f: $f_ty| $body)
+    };
+    // conditional fields
+    (@when [] [$($value:tt)*] $field:block) => {
+        $field
+    };
+    (@when [if $condition:expr] [$($value:tt)*] $field:block) => {
+        if $condition $field
+    };
+    (@unless none, $value:expr) => {
+        $value.is_some()
+    };
+    (@unless empty, $value:expr) => {
+        !$value.is_empty()
+    };
+    (@unless default, $value:expr) => {
+        {
+            use $crate::__private::IsDefault as _;
+
+            !$value.__debug_helper_is_default()
+        }
+    };
+    // `..?` in a struct ends with `finish_non_exhaustive` if `skip(...)` lists any field
+    (@skipped $(,)*) => {
+        false
+    };
+    (@skipped skip($(.$skip:tt),+ $(,)?) $($rest:tt)*) => {
+        true
+    };
+    (@skipped $t:tt $($rest:tt)*) => {
+        $crate::__debug_helper_fields!(@skipped $($rest)*)
+    };
+    // `..?` in a variant ends with `finish_non_exhaustive` if the pattern leaves out a field or binds a field which is not written
+    (@auto $fields:tt $bound:tt {$($pattern:tt)*}) => {
+        $crate::__debug_helper_fields!(@auto $fields $bound $($pattern)*)
+    };
+    (@auto $fields:tt $bound:tt ($($pattern:tt)*)) => {
+        $crate::__debug_helper_fields!(@auto $fields $bound $($pattern)*)
+    };
+    (@auto [$($fields:tt)*] [$($bound:tt)*] $(,)*) => {
+        !$crate::__private::all_listed(&[$(stringify!($bound)),*], &$crate::__debug_helper_fields!(@listed [] $($fields)*))
+    };
+    (@auto $fields:tt $bound:tt .. $($pattern:tt)*) => {
+        true
+    };
+    (@auto $fields:tt $bound:tt _ $($pattern:tt)*) => {
+        true
+    };
+    (@auto $fields:tt [$($bound:tt)*] $field:ident : ref mut $binding:ident $(, $($pattern:tt)*)?) => {
+        $crate::__debug_helper_fields!(@auto $fields [$($bound)* $binding] $($($pattern)*)?)
+    };
+    (@auto $fields:tt [$($bound:tt)*] $field:ident : ref $binding:ident $(, $($pattern:tt)*)?) => {
+        $crate::__debug_helper_fields!(@auto $fields [$($bound)* $binding] $($($pattern)*)?)
+    };
+    (@auto $fields:tt [$($bound:tt)*] $field:ident : mut $binding:ident $(, $($pattern:tt)*)?) => {
+        $crate::__debug_helper_fields!(@auto $fields [$($bound)* $binding] $($($pattern)*)?)
+    };
+    (@auto $fields:tt [$($bound:tt)*] $field:ident : $binding:ident $(, $($pattern:tt)*)?) => {
+        $crate::__debug_helper_fields!(@auto $fields [$($bound)* $binding] $($($pattern)*)?)
+    };
+    (@auto $fields:tt [$($bound:tt)*] ref mut $binding:ident $(, $($pattern:tt)*)?) => {
+        $crate::__debug_helper_fields!(@auto $fields [$($bound)* $binding] $($($pattern)*)?)
+    };
+    (@auto $fields:tt [$($bound:tt)*] ref $binding:ident $(, $($pattern:tt)*)?) => {
+        $crate::__debug_helper_fields!(@auto $fields [$($bound)* $binding] $($($pattern)*)?)
+    };
+    (@auto $fields:tt [$($bound:tt)*] mut $binding:ident $(, $($pattern:tt)*)?) => {
+        $crate::__debug_helper_fields!(@auto $fields [$($bound)* $binding] $($($pattern)*)?)
+    };
+    (@auto $fields:tt [$($bound:tt)*] $binding:ident $(, $($pattern:tt)*)?) => {
+        $crate::__debug_helper_fields!(@auto $fields [$($bound)* $binding] $($($pattern)*)?)
+    };
+    // a nested pattern cannot be written as a whole
+    (@auto $fields:tt $bound:tt $($pattern:tt)*) => {
+        true
+    };
+    // the names of the written fields, `let .name = ...` writes a bound field with the same name
+    (@listed [$($listed:tt)*] $(,)*) => {
+        [$(stringify!($listed)),*]
+    };
+    (@listed [$($listed:tt)*] let .$field:tt = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__debug_helper_fields!(@listed [$($listed)* $field] $($($rest)*)?)
+    };
+    (@listed [$($listed:tt)*] .$field:tt $($rest:tt)*) => {
+        $crate::__debug_helper_fields!(@next [$($listed)* $field] $($rest)*)
+    };
+    (@listed [$($listed:tt)*] (.$field:tt, $($fmt:tt)+) $($rest:tt)*) => {
+        $crate::__debug_helper_fields!(@next [$($listed)* $field] $($rest)*)
+    };
+    (@listed $listed:tt $($rest:tt)*) => {
+        $crate::__debug_helper_fields!(@next $listed $($rest)*)
+    };
+    (@next $listed:tt) => {
+        $crate::__debug_helper_fields!(@listed $listed)
+    };
+    (@next $listed:tt , $($rest:tt)*) => {
+        $crate::__debug_helper_fields!(@listed $listed $($rest)*)
+    };
+    (@next $listed:tt $t:tt $($rest:tt)*) => {
+        $crate::__debug_helper_fields!(@next $listed $($rest)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __debug_helper_modifier {
+    (, $value:expr) => {
+        $value
+    };
+    (redacted, $value:expr) => {
+        $crate::Redacted::new($value)
+    };
+    (redacted($placeholder:literal), $value:expr) => {
+        $crate::Redacted::with_placeholder($value, $placeholder)
+    };
+    (redacted(len), $value:expr) => {
+        $crate::Redacted::with_len($value)
+    };
+    (redacted(hash), $value:expr) => {
+        $crate::Redacted::with_hash($value)
+    };
+    (hex $(($width:expr))?, $value:expr) => {
+        $crate::__debug_helper_modifier!(@radix LowerHex $($width)?, $value)
+    };
+    (upper_hex $(($width:expr))?, $value:expr) => {
+        $crate::__debug_helper_modifier!(@radix UpperHex $($width)?, $value)
+    };
+    (binary $(($width:expr))?, $value:expr) => {
+        $crate::__debug_helper_modifier!(@radix Binary $($width)?, $value)
+    };
+    (octal $(($width:expr))?, $value:expr) => {
+        $crate::__debug_helper_modifier!(@radix Octal $($width)?, $value)
+    };
+    (@radix $radix:ident, $value:expr) => {
+        $crate::__debug_helper_modifier!(@radix $radix 0, $value)
+    };
+    (@radix $radix:ident $width:expr, $value:expr) => {
+        {
+            use $crate::__private::RadixDebug as _;
+
+            $value.__debug_helper_radix($crate::__private::Radix::$radix, $width)
+        }
+    };
+    (display, $value:expr) => {
+        $crate::DisplayAsDebug($value)
+    };
+    (hex_dump, $value:expr) => {
+        $crate::HexDump::new($value)
+    };
+    (truncate($limit:expr), $value:expr) => {{
+        use $crate::__private::Iterable as _;
+
+        $crate::Truncated::new($value.__debug_helper_iterable(), $limit)
+    }};
+    (truncate_str($limit:expr), $value:expr) => {
+        $crate::TruncatedStr::new($value, $limit)
+    };
+    (truncate_bytes($limit:expr), $value:expr) => {
+        $crate::TruncatedBytes::new($value, $limit)
+    };
+    (truncate_map($limit:expr), $value:expr) => {{
+        use $crate::__private::Iterable as _;
+
+        $crate::TruncatedMap::new($value.__debug_helper_iterable(), $limit)
+    }};
+}
+
+#[macro_export]
+macro_rules! fmt_struct {
+    // TODO unit struct
+    (@[$($name:tt)*] $formatter:expr $(, $self:expr)? $(,)*) => {
+        $crate::__debug_helper_builder!(@unit $formatter, $($name)*)
+    };
+    // TODO struct
+    (@[$($name:tt)*] $formatter:expr, $self:expr, $($fields:tt)*) => {
+        $crate::__debug_helper_builder!(debug_struct, $formatter, builder, $($name)*, {
+            $crate::__debug_helper_fields!(@check [$self] [] () $($fields)*);
+
+            $crate::__debug_helper_fields!(@struct builder [$self.] [$crate::__debug_helper_fields!(@skipped $($fields)*)] $($fields)*)
+        })
+    };
+    // TODO struct name
+    (@name [Self], $($rest:tt)*) => {
+        $crate::fmt_struct!(@[[Self]] $($rest)*)
+    };
+    (@name [$struct_name:literal], $($rest:tt)*) => {
+        $crate::fmt_struct!(@[[] $struct_name] $($rest)*)
+    };
+    (@name [$($struct_name:tt)+], $($rest:tt)*) => {
+        $crate::fmt_struct!(@[[] stringify!($($struct_name)+)] $($rest)*)
+    };
+    ($($t:tt)+) => {
+        $crate::__debug_helper_type_name!(fmt_struct {@name} [] [] $($t)+)
+    };
+}
+
+#[macro_export]
+macro_rules! fmt_tuple_struct {
+    // TODO unit tuple struct
+    (@[$($name:tt)*] $formatter:expr $(, $self:expr)? $(,)*) => {
+        $crate::__debug_helper_builder!(@unit $formatter, $($name)*)
+    };
+    // TODO tuple struct
+    (@[$($name:tt)*] $formatter:expr, $self:expr, $($fields:tt)*) => {
+        $crate::__debug_helper_builder!(debug_tuple, $formatter, builder, $($name)*, {
+            $crate::__debug_helper_fields!(@check [$self] [] () $($fields)*);
+
+            $crate::__debug_helper_fields!(@tuple builder [$self.] $($fields)*)
+        })
+    };
+    // TODO tuple struct name
+    (@name [Self], $($rest:tt)*) => {
+        $crate::fmt_tuple_struct!(@[[Self]] $($rest)*)
+    };
+    (@name [$struct_name:literal], $($rest:tt)*) => {
+        $crate::fmt_tuple_struct!(@[[] $struct_name] $($rest)*)
+    };
+    (@name [$($struct_name:tt)+], $($rest:tt)*) => {
+        $crate::fmt_tuple_struct!(@[[] stringify!($($struct_name)+)] $($rest)*)
+    };
+    ($($t:tt)+) => {
+        $crate::__debug_helper_type_name!(fmt_tuple_struct {@name} [] [] $($t)+)
+    };
+}
+
+#[macro_export]
+macro_rules! fmt_enum {
+    // TODO enum
+    (@[$enum_name:tt] {$( $($variant_unit:ident $(as $unit_name:tt)?)? $(($variant_tuple:ident $tuple:tt $(as $tuple_name:tt)? $(:($($t_fields:tt)*))?))? $({$variant_struct:ident $struct:tt $(as $struct_name:tt)? $(:($($s_fields:tt)*))?})? ),+ $(,)*}, $formatter:expr, $self:expr $(,)*) => {
+        match $self {
+            $(
+                $(
+                    Self::$variant_unit => {
+                        $crate::__debug_helper_builder!(debug_tuple, $formatter, builder, $enum_name $crate::__debug_helper_name!($variant_unit $(as $unit_name)?), {
+                            builder.finish()
+                        })
+                    }
+                )?
+                $(
+                    Self::$variant_tuple $tuple => {
+                        $crate::__debug_helper_builder!(debug_tuple, $formatter, builder, $enum_name $crate::__debug_helper_name!($variant_tuple $(as $tuple_name)?), {
+                            $crate::__debug_helper_fields!(@tuple builder [*] $($($t_fields)*)?)
+                        })
+                    }
+                )?
+                $(
+                    Self::$variant_struct $struct => {
+                        $crate::__debug_helper_builder!(debug_struct, $formatter, builder, $enum_name $crate::__debug_helper_name!($variant_struct $(as $struct_name)?), {
+                            $crate::__debug_helper_fields!(@struct builder [*] [$crate::__debug_helper_fields!(@auto [$($($s_fields)*)?] [] $struct)] $($($s_fields)*)?)
+                        })
+                    }
+                )?
+            )+
+        }
+    };
+    // TODO enum full path
+    (@full [$($enum_name:tt)*] [] [:: $variant:ident $(, $($variants:tt)*)?] $($rest:tt)*) => {
+        $crate::fmt_enum!(@mode [$($enum_name)*] [name] {$variant $(, $($variants)*)?} $($rest)*)
+    };
+    (@full [$($enum_name:tt)*] [] [:: $variant:ident as $($variants:tt)*] $($rest:tt)*) => {
+        $crate::fmt_enum!(@mode [$($enum_name)*] [name] {$variant as $($variants)*} $($rest)*)
+    };
+    (@full [$($enum_name:tt)*] [] [:: ($($variant:tt)*) $($variants:tt)*] $($rest:tt)*) => {
+        $crate::fmt_enum!(@mode [$($enum_name)*] [name] {($($variant)*) $($variants)*} $($rest)*)
+    };
+    (@full [$($enum_name:tt)*] [] [:: {$($variant:tt)*} $($variants:tt)*] $($rest:tt)*) => {
+        $crate::fmt_enum!(@mode [$($enum_name)*] [name] {{$($variant)*} $($variants)*} $($rest)*)
+    };
+    (@full [$($enum_name:tt)*] [$($depth:tt)*] [< $($variants:tt)*] $($rest:tt)*) => {
+        $crate::fmt_enum!(@full [$($enum_name)*] [- $($depth)*] [$($variants)*] $($rest)*)
+    };
+    (@full [$($enum_name:tt)*] [- $($depth:tt)*] [> $($variants:tt)*] $($rest:tt)*) => {
+        $crate::fmt_enum!(@full [$($enum_name)*] [$($depth)*] [$($variants)*] $($rest)*)
+    };
+    (@full [$($enum_name:tt)*] [- - $($depth:tt)*] [>> $($variants:tt)*] $($rest:tt)*) => {
+        $crate::fmt_enum!(@full [$($enum_name)*] [$($depth)*] [$($variants)*] $($rest)*)
+    };
+    (@full [$($enum_name:tt)*] [- $($depth:tt)*] [$t:tt $($variants:tt)*] $($rest:tt)*) => {
+        $crate::fmt_enum!(@full [$($enum_name)*] [- $($depth)*] [$($variants)*] $($rest)*)
+    };
+    (@full [$($enum_name:tt)*] [] [$t:tt $($variants:tt)*] $($rest:tt)*) => {
+        $crate::fmt_enum!(@full [$($enum_name)* $t] [] [$($variants)*] $($rest)*)
+    };
+    ({$($variants:tt)*} $($rest:tt)*) => {
+        $crate::fmt_enum!(@full [] [] [$($variants)*] $($rest)*)
+    };
+    // TODO enum name modes
+    (@mode [Self] [name] $($rest:tt)*) => {
+        $crate::fmt_enum!(@[[Self]] $($rest)*)
+    };
+    (@mode [$($enum_name:tt)*] [] $($rest:tt)*) => {
+        $crate::fmt_enum!(@[[]] $($rest)*)
+    };
+    (@mode [$($enum_name:tt)*] [name] $($rest:tt)*) => {
+        $crate::fmt_enum!(@[[stringify!($($enum_name)*)]] $($rest)*)
+    };
+    (@mode [$($enum_name:tt)*] [path] $($rest:tt)*) => {
+        $crate::fmt_enum!(@[[module_path!(), "::", stringify!($($enum_name)*)]] $($rest)*)
+    };
+    (@mode [$($enum_name:tt)*] [Self] $($rest:tt)*) => {
+        $crate::fmt_enum!(@[[Self]] $($rest)*)
+    };
+    (@mode [$($enum_name:tt)*] [$prefix:literal] $($rest:tt)*) => {
+        $crate::fmt_enum!(@[[$prefix]] $($rest)*)
+    };
+    (@mode [$($enum_name:tt)*] [std] {$($variants:tt)*} $($rest:tt)*) => {
+        $crate::fmt_enum!(@std [] [$($variants)*] $($rest)*)
+    };
+    // TODO std compatibility, variants without field lists write the fields bound by their patterns
+    (@std [$([$($done:tt)*])*] [$(,)*] $($rest:tt)*) => {
+        $crate::fmt_enum!(@[[]] {$($($done)*),*} $($rest)*)
+    };
+    (@std $done:tt [($variant:ident ($($bindings:tt)*) $(as $name:tt)?) $(, $($todo:tt)*)?] $($rest:tt)*) => {
+        $crate::fmt_enum!(@std_tuple $done [$variant ($($bindings)*) $(as $name)?] [] [$($bindings)*] [$($($todo)*)?] $($rest)*)
+    };
+    (@std $done:tt [{$variant:ident {$($bindings:tt)*} $(as $name:tt)?} $(, $($todo:tt)*)?] $($rest:tt)*) => {
+        $crate::fmt_enum!(@std_struct $done [$variant {$($bindings)*} $(as $name)?] [] [$($bindings)*] [$($($todo)*)?] $($rest)*)
+    };
+    (@std [$($done:tt)*] [$variant:ident as $name:tt $(, $($todo:tt)*)?] $($rest:tt)*) => {
+        $crate::fmt_enum!(@std [$($done)* [$variant as $name]] [$($($todo)*)?] $($rest)*)
+    };
+    (@std [$($done:tt)*] [$variant:tt $(, $($todo:tt)*)?] $($rest:tt)*) => {
+        $crate::fmt_enum!(@std [$($done)* [$variant]] [$($($todo)*)?] $($rest)*)
+    };
+    (@std_tuple [$($done:tt)*] [$($variant:tt)*] [$($fields:tt)*] [] $todo:tt $($rest:tt)*) => {
+        $crate::fmt_enum!(@std [$($done)* [($($variant)*: ($($fields)*))]] $todo $($rest)*)
+    };
+    (@std_tuple $done:tt $variant:tt $fields:tt [_ $(, $($bindings:tt)*)?] $($rest:tt)*) => {
+        $crate::fmt_enum!(@std_tuple $done $variant $fields [$($($bindings)*)?] $($rest)*)
+    };
+    (@std_tuple $done:tt $variant:tt $fields:tt [.. $(, $($bindings:tt)*)?] $($rest:tt)*) => {
+        $crate::fmt_enum!(@std_tuple $done $variant $fields [$($($bindings)*)?] $($rest)*)
+    };
+    (@std_tuple $done:tt $variant:tt $fields:tt [ref mut $binding:ident $($bindings:tt)*] $($rest:tt)*) => {
+        compile_error!(concat!("`as std` cannot write the `ref mut ", stringify!($binding), "` binding"))
+    };
+    (@std_tuple $done:tt $variant:tt $fields:tt [mut $binding:ident $($bindings:tt)*] $($rest:tt)*) => {
+        compile_error!(concat!("`as std` cannot write the `mut ", stringify!($binding), "` binding"))
+    };
+    (@std_tuple $done:tt $variant:tt [$($fields:tt)*] [ref $binding:ident $(, $($bindings:tt)*)?] $($rest:tt)*) => {
+        $crate::fmt_enum!(@std_tuple $done $variant [$($fields)* .$binding,] [$($($bindings)*)?] $($rest)*)
+    };
+    (@std_tuple $done:tt $variant:tt [$($fields:tt)*] [$binding:ident $(, $($bindings:tt)*)?] $($rest:tt)*) => {
+        $crate::fmt_enum!(@std_tuple $done $variant [$($fields)* .$binding,] [$($($bindings)*)?] $($rest)*)
+    };
+    (@std_struct [$($done:tt)*] [$($variant:tt)*] [$($fields:tt)*] [] $todo:tt $($rest:tt)*) => {
+        $crate::fmt_enum!(@std [$($done)* [{$($variant)*: ($($fields)*)}]] $todo $($rest)*)
+    };
+    (@std_struct $done:tt $variant:tt $fields:tt [.. $(, $($bindings:tt)*)?] $($rest:tt)*) => {
+        $crate::fmt_enum!(@std_struct $done $variant $fields [$($($bindings)*)?] $($rest)*)
+    };
+    (@std_struct $done:tt $variant:tt $fields:tt [$field:ident: _ $(, $($bindings:tt)*)?] $($rest:tt)*) => {
+        $crate::fmt_enum!(@std_struct $done $variant $fields [$($($bindings)*)?] $($rest)*)
+    };
+    (@std_struct $done:tt $variant:tt $fields:tt [ref mut $binding:ident $($bindings:tt)*] $($rest:tt)*) => {
+        compile_error!(concat!("`as std` cannot write the `ref mut ", stringify!($binding), "` binding"))
+    };
+    (@std_struct $done:tt $variant:tt $fields:tt [$field:ident: ref mut $binding:ident $($bindings:tt)*] $($rest:tt)*) => {
+        compile_error!(concat!("`as std` cannot write the `ref mut ", stringify!($binding), "` binding"))
+    };
+    (@std_struct $done:tt $variant:tt $fields:tt [mut $binding:ident $($bindings:tt)*] $($rest:tt)*) => {
+        compile_error!(concat!("`as std` cannot write the `mut ", stringify!($binding), "` binding"))
+    };
+    (@std_struct $done:tt $variant:tt $fields:tt [$field:ident: mut $binding:ident $($bindings:tt)*] $($rest:tt)*) => {
+        compile_error!(concat!("`as std` cannot write the `mut ", stringify!($binding), "` binding"))
+    };
+    (@std_struct $done:tt $variant:tt [$($fields:tt)*] [$field:ident: ref $binding:ident $(, $($bindings:tt)*)?] $($rest:tt)*) => {
+        $crate::fmt_enum!(@std_struct $done $variant [$($fields)* .$binding as $field,] [$($($bindings)*)?] $($rest)*)
+    };
+    (@std_struct $done:tt $variant:tt [$($fields:tt)*] [ref $field:ident $(, $($bindings:tt)*)?] $($rest:tt)*) => {
+        $crate::fmt_enum!(@std_struct $done $variant [$($fields)* .$field,] [$($($bindings)*)?] $($rest)*)
+    };
+    (@std_struct $done:tt $variant:tt [$($fields:tt)*] [$field:ident: $binding:ident $(, $($bindings:tt)*)?] $($rest:tt)*) => {
+        $crate::fmt_enum!(@std_struct $done $variant [$($fields)* .$binding as $field,] [$($($bindings)*)?] $($rest)*)
+    };
+    (@std_struct $done:tt $variant:tt [$($fields:tt)*] [$field:ident $(, $($bindings:tt)*)?] $($rest:tt)*) => {
+        $crate::fmt_enum!(@std_struct $done $variant [$($fields)* .$field,] [$($($bindings)*)?] $($rest)*)
+    };
+    // TODO enum name
+    (@name [$($enum_name:tt)*] [] :: {$($variants:tt)*} $(as $mode:tt)?, $($rest:tt)*) => {
+        $crate::fmt_enum!(@mode [$($enum_name)*] [$($mode)?] {$($variants)*}, $($rest)*)
+    };
+    (@name [$($enum_name:tt)*] [$($depth:tt)*] < $($rest:tt)*) => {
+        $crate::fmt_enum!(@name [$($enum_name)*] [- $($depth)*] $($rest)*)
+    };
+    (@name [$($enum_name:tt)*] [- $($depth:tt)*] > $($rest:tt)*) => {
+        $crate::fmt_enum!(@name [$($enum_name)*] [$($depth)*] $($rest)*)
+    };
+    (@name [$($enum_name:tt)*] [- - $($depth:tt)*] >> $($rest:tt)*) => {
+        $crate::fmt_enum!(@name [$($enum_name)*] [$($depth)*] $($rest)*)
+    };
+    (@name [$($enum_name:tt)*] [- $($depth:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::fmt_enum!(@name [$($enum_name)*] [- $($depth)*] $($rest)*)
+    };
+    (@name [$($enum_name:tt)*] [] $t:tt $($rest:tt)*) => {
+        $crate::fmt_enum!(@name [$($enum_name)* $t] [] $($rest)*)
+    };
+    ($($t:tt)+) => {
+        $crate::fmt_enum!(@name [] [] $($t)+)
+    };
+}
+
+/// Formats a union through the field selected by `unsafe match`, or through its raw bytes with `unsafe opaque`.
+///
+/// # Safety
+///
+/// `unsafe` is a promise of the caller. A selected field must be the active field of the union. `opaque` reads all `size_of_val` bytes of the union, so every one of them must be initialized, which means that the union has no padding and its value was written through a field covering the whole union.
+///
+/// The `unsafe` keyword covers only the reads of the union, not the expression of the union itself.
+///
+/// ```compile_fail,E0133
+/// use std::fmt::{self, Debug, Formatter};
+///
+/// union U {
+///     a: u8,
+/// }
+///
+/// struct Ptr(*const U);
+///
+/// impl Debug for Ptr {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+///         // dereferencing the raw pointer needs an `unsafe` block of its own
+///         debug_helper::impl_debug_for_union!(U, f, *self.0, unsafe match () {
+///             _ => .a,
+///         });
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! fmt_union {
+    // TODO union fields
+    (@field [$($name:tt)*] [$formatter:expr] [$self:expr] .$field:tt $(as $field_name:tt)? $(: $modifier:ident $(($($args:tt)*))?)?) => {
+        $crate::__debug_helper_builder!(debug_struct, $formatter, builder, $($name)*, {
+            // the union is evaluated outside of the `unsafe` block, which only covers the field read
+            let value = &$self;
+
+            builder.field($crate::__debug_helper_name!($field $(as $field_name)?), &$crate::__debug_helper_modifier!($($modifier $(($($args)*))?)?, unsafe { &value.$field }));
+
+            builder.finish()
+        })
+    };
+    // TODO opaque unions, every byte of the union must be initialized
+    (@opaque [$($name:tt)*] [$formatter:expr] [$self:expr]) => {
+        $crate::__debug_helper_builder!(debug_tuple, $formatter, builder, $($name)*, {
+            let value = $self;
+
+            builder.field(&$crate::HexDump::new(unsafe {
+                ::core::slice::from_raw_parts(value as *const _ as *const u8, ::core::mem::size_of_val(value))
+            }));
+
+            builder.finish()
+        })
+    };
+    // TODO union match arms
+    (@arms $name:tt $formatter:tt $self:tt [$($selector:tt)*] [$($arms:tt)*] $(,)*) => {
+        match $($selector)* {
+            $($arms)*
+        }
+    };
+    (@arms $name:tt $formatter:tt $self:tt $selector:tt [$($arms:tt)*] $pattern:pat => opaque $(, $($rest:tt)*)?) => {
+        $crate::fmt_union!(@arms $name $formatter $self $selector [$($arms)* $pattern => $crate::fmt_union!(@opaque $name $formatter $self),] $($($rest)*)?)
+    };
+    (@arms $name:tt $formatter:tt $self:tt $selector:tt [$($arms:tt)*] $pattern:pat => .$field:tt $(as $field_name:tt)? $(: $modifier:ident $(($($args:tt)*))?)? $(, $($rest:tt)*)?) => {
+        $crate::fmt_union!(@arms $name $formatter $self $selector [$($arms)* $pattern => $crate::fmt_union!(@field $name $formatter $self .$field $(as $field_name)? $(: $modifier $(($($args)*))?)?),] $($($rest)*)?)
+    };
+    // TODO union selector
+    (@select $name:tt $formatter:tt $self:tt [$($selector:tt)*] {$($arms:tt)*} $(,)*) => {
+        $crate::fmt_union!(@arms $name $formatter $self [$($selector)*] [] $($arms)*)
+    };
+    (@select $name:tt $formatter:tt $self:tt [$($selector:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::fmt_union!(@select $name $formatter $self [$($selector)* $t] $($rest)*)
+    };
+    (@[$($name:tt)*] $formatter:expr, $self:expr, unsafe opaque $(,)*) => {
+        $crate::fmt_union!(@opaque [$($name)*] [$formatter] [$self])
+    };
+    (@[$($name:tt)*] $formatter:expr, $self:expr, unsafe match $($selector:tt)+) => {
+        $crate::fmt_union!(@select [$($name)*] [$formatter] [$self] [] $($selector)+)
+    };
+    // TODO union name
+    (@name [Self], $($rest:tt)*) => {
+        $crate::fmt_union!(@[[Self]] $($rest)*)
+    };
+    (@name [$union_name:literal], $($rest:tt)*) => {
+        $crate::fmt_union!(@[[] $union_name] $($rest)*)
+    };
+    (@name [$($union_name:tt)+], $($rest:tt)*) => {
+        $crate::fmt_union!(@[[] stringify!($($union_name)+)] $($rest)*)
+    };
+    ($($t:tt)+) => {
+        $crate::__debug_helper_type_name!(fmt_union {@name} [] [] $($t)+)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __debug_helper_entries {
+    // `debug_list` and `debug_set` builders
+    ($method:ident, $formatter:expr, $iter:expr $(,)*) => {
+        $formatter.$method().entries($iter).finish()
+    };
+    ($method:ident, $formatter:expr, $iter:expr, |$entry:pat_param| $fmt:literal $(, $args:expr)* $(,)*) => {
+        {
+            let mut builder = $formatter.$method();
+
+            for $entry in $iter {
+                builder.entry(&$crate::__private::RawArgs(format_args!($fmt $(, $args)*)));
+            }
+
+            builder.finish()
+        }
+    };
+    ($method:ident, $formatter:expr, $iter:expr, |$entry:pat_param| $value:expr $(,)*) => {
+        {
+            let mut builder = $formatter.$method();
+
+            for $entry in $iter {
+                builder.entry(&$value);
+            }
+
+            builder.finish()
+        }
+    };
+    // `debug_map` builders
+    (@map $formatter:expr, $iter:expr $(,)*) => {
+        $formatter.debug_map().entries($iter).finish()
+    };
+    (@map $formatter:expr, $iter:expr, |$entry:pat_param| $key:expr => $fmt:literal $(, $args:expr)* $(,)*) => {
+        {
+            let mut builder = $formatter.debug_map();
+
+            for $entry in $iter {
+                builder.entry(&$key, &$crate::__private::RawArgs(format_args!($fmt $(, $args)*)));
+            }
+
+            builder.finish()
+        }
+    };
+    (@map $formatter:expr, $iter:expr, |$entry:pat_param| $key:expr => $value:expr $(,)*) => {
+        {
+            let mut builder = $formatter.debug_map();
+
+            for $entry in $iter {
+                builder.entry(&$key, &$value);
+            }
+
+            builder.finish()
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! fmt_list {
+    ($($t:tt)*) => {
+        $crate::__debug_helper_entries!(debug_list, $($t)*)
+    };
+}
+
+#[macro_export]
+macro_rules! fmt_set {
+    ($($t:tt)*) => {
+        $crate::__debug_helper_entries!(debug_set, $($t)*)
+    };
+}
+
+#[macro_export]
+macro_rules! fmt_map {
+    ($($t:tt)*) => {
+        $crate::__debug_helper_entries!(@map $($t)*)
+    };
+}
+
+#[macro_export]
+macro_rules! impl_debug_for_struct {
+    ($($t:tt)*) => {
+        return $crate::fmt_struct!($($t)*);
+    };
+}
+
+#[macro_export]
+macro_rules! impl_debug_for_tuple_struct {
+    ($($t:tt)*) => {
+        return $crate::fmt_tuple_struct!($($t)*);
+    };
+}
+
+#[macro_export]
+macro_rules! impl_debug_for_enum {
+    ($($t:tt)*) => {
+        return $crate::fmt_enum!($($t)*);
+    };
+}
+
+/// Returns `fmt_union!` from a `Debug::fmt` method.
+///
+/// # Safety
+///
+/// The same as `fmt_union!`.
+#[macro_export]
+macro_rules! impl_debug_for_union {
+    ($($t:tt)*) => {
+        return $crate::fmt_union!($($t)*);
+    };
+}
+
+#[macro_export]
+macro_rules! impl_debug_as_list {
+    ($($t:tt)*) => {
+        return $crate::fmt_list!($($t)*);
+    };
+}
+
+#[macro_export]
+macro_rules! impl_debug_as_set {
+    ($($t:tt)*) => {
+        return $crate::fmt_set!($($t)*);
+    };
+}
+
+#[macro_export]
+macro_rules! impl_debug_as_map {
+    ($($t:tt)*) => {
+        return $crate::fmt_map!($($t)*);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __debug_helper_impl {
+    // generic parameters
+    (@generics $kind:ident [$($g:tt)*] [] > $($rest:tt)*) => {
+        $crate::__debug_helper_impl!(@type $kind [$($g)*] $($rest)*);
+    };
+    (@generics $kind:ident [$($g:tt)*] [-] >> $($rest:tt)*) => {
+        $crate::__debug_helper_impl!(@type $kind [$($g)* >] $($rest)*);
+    };
+    (@generics $kind:ident [$($g:tt)*] [$($d:tt)*] < $($rest:tt)*) => {
+        $crate::__debug_helper_impl!(@generics $kind [$($g)* <] [- $($d)*] $($rest)*);
+    };
+    (@generics $kind:ident [$($g:tt)*] [- $($d:tt)*] > $($rest:tt)*) => {
+        $crate::__debug_helper_impl!(@generics $kind [$($g)* >] [$($d)*] $($rest)*);
+    };
+    (@generics $kind:ident [$($g:tt)*] [- - $($d:tt)*] >> $($rest:tt)*) => {
+        $crate::__debug_helper_impl!(@generics $kind [$($g)* >>] [$($d)*] $($rest)*);
+    };
+    (@generics $kind:ident [$($g:tt)*] [$($d:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::__debug_helper_impl!(@generics $kind [$($g)* $t] [$($d)*] $($rest)*);
+    };
+    // self type
+    (@type $kind:ident [$($g:tt)*] $ty:ty where $($rest:tt)*) => {
+        $crate::__debug_helper_impl!(@where $kind [$($g)*] [$ty] [] $($rest)*);
+    };
+    (@type $kind:ident [$($g:tt)*] $ty:ty { $($body:tt)* }) => {
+        $crate::__debug_helper_impl!(@emit $kind [$($g)*] [$ty] [] { $($body)* });
+    };
+    // where clause
+    (@where $kind:ident [$($g:tt)*] [$ty:ty] [$($w:tt)*] { $($body:tt)* }) => {
+        $crate::__debug_helper_impl!(@emit $kind [$($g)*] [$ty] [$($w)*] { $($body)* });
+    };
+    (@where $kind:ident [$($g:tt)*] [$ty:ty] [$($w:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::__debug_helper_impl!(@where $kind [$($g)*] [$ty] [$($w)* $t] $($rest)*);
+    };
+    // impl blocks
+    (@emit enum [$($g:tt)*] [$ty:ty] [$($w:tt)*] { $($body:tt)* }) => {
+        $crate::__debug_helper_impl!(@enum [$($g)*] [$ty] [$($w)*] [] $($body)*);
+    };
+    (@emit fields [$($g:tt)*] [$ty:ty] [$($w:tt)*] { $self:ident $(, $($fields:tt)*)? }) => {
+        impl<$($g)*> $crate::DebugFields for $ty where $($w)* {
+            #[inline]
+            fn fmt_fields(&$self, builder: &mut ::core::fmt::DebugStruct<'_, '_>) {
+                $crate::__debug_helper_fields!(@check [$self] [] () $($($fields)*)?);
+
+                $crate::__debug_helper_fields!(@struct (builder) [$self.] [] $($($fields)*)?)
+            }
+        }
+    };
+    (@emit $kind:ident [$($g:tt)*] [$ty:ty] [$($w:tt)*] { $($body:tt)* }) => {
+        $crate::__debug_helper_type_name!(__debug_helper_impl {@named $kind [$($g)*] [$ty] [$($w)*]} [] [] $($body)*);
+    };
+    (@named struct [$($g:tt)*] [$ty:ty] [$($w:tt)*] [$($struct_name:tt)*] $(,)*) => {
+        impl<$($g)*> ::core::fmt::Debug for $ty where $($w)* {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::fmt_struct!($($struct_name)*, f)
+            }
+        }
+    };
+    (@named struct [$($g:tt)*] [$ty:ty] [$($w:tt)*] [$($struct_name:tt)*], $self:ident $(, $($fields:tt)*)?) => {
+        impl<$($g)*> ::core::fmt::Debug for $ty where $($w)* {
+            #[inline]
+            fn fmt(&$self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::fmt_struct!($($struct_name)*, f, $self $(, $($fields)*)?)
+            }
+        }
+    };
+    (@named tuple_struct [$($g:tt)*] [$ty:ty] [$($w:tt)*] [$($struct_name:tt)*] $(,)*) => {
+        impl<$($g)*> ::core::fmt::Debug for $ty where $($w)* {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::fmt_tuple_struct!($($struct_name)*, f)
+            }
+        }
+    };
+    (@named tuple_struct [$($g:tt)*] [$ty:ty] [$($w:tt)*] [$($struct_name:tt)*], $self:ident $(, $($fields:tt)*)?) => {
+        impl<$($g)*> ::core::fmt::Debug for $ty where $($w)* {
+            #[inline]
+            fn fmt(&$self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::fmt_tuple_struct!($($struct_name)*, f, $self $(, $($fields)*)?)
+            }
+        }
+    };
+    (@named union [$($g:tt)*] [$ty:ty] [$($w:tt)*] [$($union_name:tt)*], $self:ident, $($selector:tt)*) => {
+        impl<$($g)*> ::core::fmt::Debug for $ty where $($w)* {
+            #[inline]
+            fn fmt(&$self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::fmt_union!($($union_name)*, f, $self, $($selector)*)
+            }
+        }
+    };
+    (@enum [$($g:tt)*] [$ty:ty] [$($w:tt)*] [$($enum_name:tt)*] {$($variants:tt)*} $(as $mode:tt)?, $self:ident $(,)*) => {
+        impl<$($g)*> ::core::fmt::Debug for $ty where $($w)* {
+            #[inline]
+            fn fmt(&$self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::fmt_enum!($($enum_name)* {$($variants)*} $(as $mode)?, f, $self)
+            }
+        }
+    };
+    (@enum [$($g:tt)*] [$ty:ty] [$($w:tt)*] [$($enum_name:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::__debug_helper_impl!(@enum [$($g)*] [$ty] [$($w)*] [$($enum_name)* $t] $($rest)*);
+    };
+    // entry
+    ($kind:ident impl < $($rest:tt)*) => {
+        $crate::__debug_helper_impl!(@generics $kind [] [] $($rest)*);
+    };
+    ($kind:ident impl $($rest:tt)*) => {
+        $crate::__debug_helper_impl!(@type $kind [] $($rest)*);
+    };
+}
+
+#[macro_export]
+macro_rules! derive_debug_for_struct {
+    (impl $($t:tt)*) => {
+        $crate::__debug_helper_impl!(struct impl $($t)*);
+    };
+    ($struct_path:path $(, $($t:tt)*)?) => {
+        $crate::__debug_helper_impl!(@emit struct [] [$struct_path] [] { $struct_path $(, $($t)*)? });
+    };
+}
+
+#[macro_export]
+macro_rules! derive_debug_fields_for_struct {
+    (impl $($t:tt)*) => {
+        $crate::__debug_helper_impl!(fields impl $($t)*);
+    };
+    ($struct_path:path, $($t:tt)*) => {
+        $crate::__debug_helper_impl!(@emit fields [] [$struct_path] [] { $($t)* });
+    };
+}
+
+#[macro_export]
+macro_rules! derive_debug_for_tuple_struct {
+    (impl $($t:tt)*) => {
+        $crate::__debug_helper_impl!(tuple_struct impl $($t)*);
+    };
+    ($struct_path:path $(, $($t:tt)*)?) => {
+        $crate::__debug_helper_impl!(@emit tuple_struct [] [$struct_path] [] { $struct_path $(, $($t)*)? });
+    };
+}
+
+#[macro_export]
+macro_rules! derive_debug_for_enum {
+    (impl $($t:tt)*) => {
+        $crate::__debug_helper_impl!(enum impl $($t)*);
+    };
+    ($enum_name:ident::{$($variants:tt)*} $(as $mode:tt)?, $self:ident $(,)*) => {
+        $crate::__debug_helper_impl!(@emit enum [] [$enum_name] [] { $enum_name::{$($variants)*} $(as $mode)?, $self });
+    };
+    ({$enum_name:ident::$($variants:tt)*}, $self:ident $(,)*) => {
+        $crate::__debug_helper_impl!(@emit enum [] [$enum_name] [] { {$enum_name::$($variants)*}, $self });
+    };
+}
+
+/// Implements `Debug` for a union with `fmt_union!`.
+///
+/// # Safety
+///
+/// The generated `fmt` method is safe to call, so the promise of `unsafe` described by `fmt_union!` must hold for every value of the union. `unsafe opaque` is only sound for unions whose fields all cover the whole union without padding.
+#[macro_export]
+macro_rules! derive_debug_for_union {
+    (impl $($t:tt)*) => {
+        $crate::__debug_helper_impl!(union impl $($t)*);
+    };
+    ($union_path:path, $($t:tt)*) => {
+        $crate::__debug_helper_impl!(@emit union [] [$union_path] [] { $union_path, $($t)* });
     };
 }
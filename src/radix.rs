@@ -0,0 +1,63 @@
+use core::fmt::{self, Debug, Formatter};
+
+#[derive(Clone, Copy)]
+pub enum Radix {
+    LowerHex,
+    UpperHex,
+    Binary,
+    Octal,
+}
+
+/// Integers and slices of integers which can be printed in another radix. Method calls see through references, `Vec`s and arrays.
+pub trait RadixDebug {
+    fn fmt_radix(&self, f: &mut Formatter<'_>, radix: Radix, width: usize) -> fmt::Result;
+
+    #[inline]
+    fn __debug_helper_radix(&self, radix: Radix, width: usize) -> RadixValue<'_, Self> {
+        RadixValue {
+            value: self,
+            radix,
+            width,
+        }
+    }
+}
+
+/// A value printed in a radix, with a prefix and its digits padded with zeros to at least `width` digits.
+pub struct RadixValue<'a, T: ?Sized> {
+    value: &'a T,
+    radix: Radix,
+    width: usize,
+}
+
+impl<'a, T: RadixDebug + ?Sized> Debug for RadixValue<'a, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.value.fmt_radix(f, self.radix, self.width)
+    }
+}
+
+macro_rules! impl_radix_debug {
+    ($($t:ty),*) => {
+        $(
+            impl RadixDebug for $t {
+                fn fmt_radix(&self, f: &mut Formatter<'_>, radix: Radix, width: usize) -> fmt::Result {
+                    match radix {
+                        Radix::LowerHex => f.write_fmt(format_args!("0x{:01$x}", self, width)),
+                        Radix::UpperHex => f.write_fmt(format_args!("0x{:01$X}", self, width)),
+                        Radix::Binary => f.write_fmt(format_args!("0b{:01$b}", self, width)),
+                        Radix::Octal => f.write_fmt(format_args!("0o{:01$o}", self, width)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_debug!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<T: RadixDebug> RadixDebug for [T] {
+    #[inline]
+    fn fmt_radix(&self, f: &mut Formatter<'_>, radix: Radix, width: usize) -> fmt::Result {
+        f.debug_list().entries(self.iter().map(|v| v.__debug_helper_radix(radix, width))).finish()
+    }
+}
@@ -0,0 +1,109 @@
+use core::{
+    fmt::{self, Debug, Formatter},
+    ops::Deref,
+};
+
+#[derive(Clone, Copy)]
+enum Hint {
+    Placeholder(&'static str),
+    Len(usize),
+    Hash(u64),
+}
+
+/// A value whose `Debug` output hides the value itself.
+///
+/// ```rust
+/// use debug_helper::Redacted;
+///
+/// assert_eq!("<redacted>", format!("{:?}", Redacted::new("password")));
+/// assert_eq!(
+///     "***",
+///     format!("{:?}", Redacted::with_placeholder("password", "***"))
+/// );
+/// assert_eq!(
+///     "<redacted 8 bytes>",
+///     format!("{:?}", Redacted::with_len("password"))
+/// );
+/// assert_eq!(
+///     "<redacted #4B1A493507B3A318>",
+///     format!("{:?}", Redacted::with_hash("password"))
+/// );
+/// ```
+#[derive(Clone, Copy)]
+pub struct Redacted<T> {
+    value: T,
+    hint:  Hint,
+}
+
+impl<T> Redacted<T> {
+    /// Wraps a value which is printed as `<redacted>`.
+    #[inline]
+    pub const fn new(value: T) -> Redacted<T> {
+        Redacted::with_placeholder(value, "<redacted>")
+    }
+
+    /// Wraps a value which is printed as the given placeholder.
+    #[inline]
+    pub const fn with_placeholder(value: T, placeholder: &'static str) -> Redacted<T> {
+        Redacted {
+            value,
+            hint: Hint::Placeholder(placeholder),
+        }
+    }
+
+    /// Unwraps the value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: AsRef<[u8]>> Redacted<T> {
+    /// Wraps a value which is printed as `<redacted N bytes>`.
+    #[inline]
+    pub fn with_len(value: T) -> Redacted<T> {
+        let len = value.as_ref().len();
+
+        Redacted {
+            value,
+            hint: Hint::Len(len),
+        }
+    }
+
+    /// Wraps a value which is printed as `<redacted #HASH>`, where `HASH` is the 64-bit FNV-1a hash of its bytes. Equal values have equal hashes, which helps to tell values apart in logs.
+    ///
+    /// The hash is not cryptographic. Do not use it for low-entropy secrets such as passwords, because they can be found by brute force.
+    #[inline]
+    pub fn with_hash(value: T) -> Redacted<T> {
+        let mut hash = 0xCBF29CE484222325u64;
+
+        for &b in value.as_ref() {
+            hash ^= b as u64;
+            hash = hash.wrapping_mul(0x100000001B3);
+        }
+
+        Redacted {
+            value,
+            hint: Hint::Hash(hash),
+        }
+    }
+}
+
+impl<T> Deref for Redacted<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> Debug for Redacted<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.hint {
+            Hint::Placeholder(placeholder) => f.write_str(placeholder),
+            Hint::Len(len) => f.write_fmt(format_args!("<redacted {} bytes>", len)),
+            Hint::Hash(hash) => f.write_fmt(format_args!("<redacted #{:016X}>", hash)),
+        }
+    }
+}
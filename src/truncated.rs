@@ -0,0 +1,260 @@
+use core::{
+    ascii,
+    fmt::{self, Debug, Formatter, Write},
+};
+
+/// An iterable which is printed as a list of at most `limit` elements, followed by the number of the remaining elements.
+///
+/// ```rust
+/// use debug_helper::Truncated;
+///
+/// assert_eq!(
+///     "[0, 1, 2, ... 9,997 more]",
+///     format!("{:?}", Truncated::new(0..10000, 3))
+/// );
+/// assert_eq!("[0, 1]", format!("{:?}", Truncated::new(&vec![0, 1], 3)));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Truncated<I> {
+    iter:  I,
+    limit: usize,
+}
+
+impl<I> Truncated<I> {
+    /// Wraps an iterable, such as a reference to a collection.
+    ///
+    /// The remaining elements are counted from the exact size hint of the iterator if it has one, and by walking them otherwise, so the iterator must be finite.
+    #[inline]
+    pub const fn new(iter: I, limit: usize) -> Truncated<I> {
+        Truncated {
+            iter,
+            limit,
+        }
+    }
+}
+
+impl<I: IntoIterator + Clone> Debug for Truncated<I>
+where
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        let mut iter = self.iter.clone().into_iter();
+
+        for item in iter.by_ref().take(self.limit) {
+            list.entry(&item);
+        }
+
+        let more = remaining(iter);
+
+        if more > 0 {
+            list.entry(&More(more));
+        }
+
+        list.finish()
+    }
+}
+
+/// An iterable of key-value pairs which is printed as a map of at most `limit` entries, followed by the number of the remaining entries.
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// use debug_helper::TruncatedMap;
+///
+/// let map: BTreeMap<u32, u32> = (0..10000).map(|i| (i, i * 2)).collect();
+///
+/// assert_eq!(
+///     "{0: 0, 1: 2, ... 9,998 more}",
+///     format!("{:?}", TruncatedMap::new(&map, 2))
+/// );
+/// ```
+#[derive(Clone, Copy)]
+pub struct TruncatedMap<I> {
+    iter:  I,
+    limit: usize,
+}
+
+impl<I> TruncatedMap<I> {
+    /// Wraps an iterable of key-value pairs, such as a reference to a map.
+    ///
+    /// Like `Truncated::new`, an iterator without an exact size hint is walked to count the remaining entries, so it must be finite.
+    #[inline]
+    pub const fn new(iter: I, limit: usize) -> TruncatedMap<I> {
+        TruncatedMap {
+            iter,
+            limit,
+        }
+    }
+}
+
+impl<I: IntoIterator<Item = (K, V)> + Clone, K: Debug, V: Debug> Debug for TruncatedMap<I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // `DebugMap` cannot have an entry without a value, so the entries are written by hand into a set
+        let mut set = f.debug_set();
+        let mut iter = self.iter.clone().into_iter();
+
+        for (key, value) in iter.by_ref().take(self.limit) {
+            set.entry(&Entry(key, value));
+        }
+
+        let more = remaining(iter);
+
+        if more > 0 {
+            set.entry(&More(more));
+        }
+
+        set.finish()
+    }
+}
+
+/// A string which is printed like a `str`, but cut at a char boundary after at most `limit` bytes, followed by an ellipsis and its total length.
+///
+/// ```rust
+/// use debug_helper::TruncatedStr;
+///
+/// assert_eq!(
+///     "\"Hello\"... (13 bytes)",
+///     format!("{:?}", TruncatedStr::new("Hello, world!", 5))
+/// );
+/// assert_eq!(
+///     "\"Hello, world!\"",
+///     format!("{:?}", TruncatedStr::new("Hello, world!", 20))
+/// );
+/// ```
+#[derive(Clone, Copy)]
+pub struct TruncatedStr<S> {
+    s:     S,
+    limit: usize,
+}
+
+impl<S> TruncatedStr<S> {
+    /// Wraps a string.
+    #[inline]
+    pub const fn new(s: S, limit: usize) -> TruncatedStr<S> {
+        TruncatedStr {
+            s,
+            limit,
+        }
+    }
+}
+
+impl<S: AsRef<str>> Debug for TruncatedStr<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = self.s.as_ref();
+
+        if s.len() <= self.limit {
+            return Debug::fmt(s, f);
+        }
+
+        let mut end = self.limit;
+
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        Debug::fmt(&s[..end], f)?;
+        write_total_len(f, s.len())
+    }
+}
+
+/// Bytes which are printed like a byte string literal, but cut after at most `limit` bytes, followed by an ellipsis and their total length.
+///
+/// ```rust
+/// use debug_helper::TruncatedBytes;
+///
+/// assert_eq!(
+///     "b\"GET /\\r\\n\"",
+///     format!("{:?}", TruncatedBytes::new(b"GET /\r\n", 16))
+/// );
+/// assert_eq!(
+///     "b\"\\x00\\xff\"... (3 bytes)",
+///     format!("{:?}", TruncatedBytes::new([0, 255, 1], 2))
+/// );
+/// ```
+#[derive(Clone, Copy)]
+pub struct TruncatedBytes<B> {
+    b:     B,
+    limit: usize,
+}
+
+impl<B> TruncatedBytes<B> {
+    /// Wraps bytes.
+    #[inline]
+    pub const fn new(b: B, limit: usize) -> TruncatedBytes<B> {
+        TruncatedBytes {
+            b,
+            limit,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> Debug for TruncatedBytes<B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let b = self.b.as_ref();
+
+        f.write_str("b\"")?;
+
+        for &c in b.iter().take(self.limit) {
+            for e in ascii::escape_default(c) {
+                f.write_char(e as char)?;
+            }
+        }
+
+        f.write_str("\"")?;
+
+        if b.len() > self.limit {
+            write_total_len(f, b.len())?;
+        }
+
+        Ok(())
+    }
+}
+
+struct Entry<K, V>(K, V);
+
+impl<K: Debug, V: Debug> Debug for Entry<K, V> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)?;
+        f.write_str(": ")?;
+        self.1.fmt(f)
+    }
+}
+
+/// The summary of the remaining elements, like `... 9,995 more`.
+struct More(usize);
+
+impl Debug for More {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("... ")?;
+        write_thousands(f, self.0)?;
+        f.write_str(" more")
+    }
+}
+
+/// Counts the remaining items, without walking them if the iterator knows its exact size.
+#[inline]
+fn remaining<I: Iterator>(iter: I) -> usize {
+    match iter.size_hint() {
+        (lower, Some(upper)) if lower == upper => lower,
+        _ => iter.count(),
+    }
+}
+
+fn write_total_len(f: &mut Formatter<'_>, len: usize) -> fmt::Result {
+    f.write_str("... (")?;
+    write_thousands(f, len)?;
+    f.write_str(" bytes)")
+}
+
+fn write_thousands(f: &mut Formatter<'_>, n: usize) -> fmt::Result {
+    if n >= 1000 {
+        write_thousands(f, n / 1000)?;
+
+        f.write_fmt(format_args!(",{:03}", n % 1000))
+    } else {
+        f.write_fmt(format_args!("{}", n))
+    }
+}
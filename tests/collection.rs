@@ -0,0 +1,140 @@
+use std::fmt::{self, Debug, Formatter};
+
+struct Ring {
+    buf:  [u8; 4],
+    head: usize,
+    len:  usize,
+}
+
+impl Ring {
+    fn iter(&self) -> impl Iterator<Item = &u8> {
+        (0..self.len).map(move |i| &self.buf[(self.head + i) % self.buf.len()])
+    }
+}
+
+fn ring() -> Ring {
+    Ring {
+        buf: [3, 4, 1, 2], head: 2, len: 3
+    }
+}
+
+#[test]
+fn list() {
+    struct Outer(Ring);
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_as_list!(f, self.0.iter());
+        }
+    }
+
+    let outer = Outer(ring());
+
+    assert_eq!("[1, 2, 3]", format!("{:?}", outer));
+    assert_eq!("[\n    1,\n    2,\n    3,\n]", format!("{:#?}", outer));
+    assert_eq!("[01, 02, 03]", format!("{:02?}", outer));
+}
+
+#[test]
+fn list_projection() {
+    struct Outer(Ring);
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_as_list!(f, self.0.iter().enumerate(), |(i, v)| i * 10
+                + *v as usize);
+        }
+    }
+
+    let outer = Outer(ring());
+
+    assert_eq!("[1, 12, 23]", format!("{:?}", outer));
+}
+
+#[test]
+fn list_fmt() {
+    struct Outer(Ring);
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_as_list!(f, self.0.iter(), |v| "0x{:02X}", v);
+        }
+    }
+
+    let outer = Outer(ring());
+
+    assert_eq!("[0x01, 0x02, 0x03]", format!("{:?}", outer));
+    assert_eq!("[\n    0x01,\n    0x02,\n    0x03,\n]", format!("{:#?}", outer));
+}
+
+#[test]
+fn set() {
+    struct Outer(Ring);
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_as_set!(f, self.0.iter());
+        }
+    }
+
+    struct Names(Vec<&'static str>);
+
+    impl Debug for Names {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_as_set!(f, self.0.iter(), |name| "{}", name);
+        }
+    }
+
+    assert_eq!("{1, 2, 3}", format!("{:?}", Outer(ring())));
+    assert_eq!("{\n    1,\n    2,\n    3,\n}", format!("{:#?}", Outer(ring())));
+    assert_eq!("{a, b}", format!("{:?}", Names(vec!["a", "b"])));
+}
+
+#[test]
+fn map() {
+    struct Slots(Vec<Option<(&'static str, u8)>>);
+
+    impl Debug for Slots {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_as_map!(f, self.0.iter().flatten().map(|&(k, v)| (k, v)));
+        }
+    }
+
+    struct Indexed(Vec<u8>);
+
+    impl Debug for Indexed {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_as_map!(f, self.0.iter().enumerate(), |(i, v)| i => v);
+        }
+    }
+
+    struct Hex(Vec<(&'static str, u8)>);
+
+    impl Debug for Hex {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_as_map!(f, self.0.iter(), |(k, v)| k => "0x{:02X}", v);
+        }
+    }
+
+    let slots = Slots(vec![Some(("a", 1)), None, Some(("b", 2))]);
+
+    assert_eq!("{\"a\": 1, \"b\": 2}", format!("{:?}", slots));
+    assert_eq!("{\n    \"a\": 1,\n    \"b\": 2,\n}", format!("{:#?}", slots));
+    assert_eq!("{0: 5, 1: 6}", format!("{:?}", Indexed(vec![5, 6])));
+    assert_eq!("{\"a\": 0x0A}", format!("{:?}", Hex(vec![("a", 10)])));
+}
+
+#[test]
+fn expression() {
+    struct Outer(Vec<u8>);
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            f.write_str("Outer")?;
+
+            debug_helper::fmt_list!(f, self.0.iter())
+        }
+    }
+
+    assert_eq!("Outer[1, 2]", format!("{:?}", Outer(vec![1, 2])));
+}
@@ -0,0 +1,25 @@
+use std::fmt::{self, Formatter};
+
+use debug_helper::DebugFn;
+
+#[test]
+fn debug_fn() {
+    let name = "Hi";
+
+    let v = DebugFn(|f| write!(f, "<{}>", name));
+
+    assert_eq!("<Hi>", format!("{:?}", v));
+    assert_eq!("<Hi>", format!("{}", v));
+    assert_eq!("[<Hi>, <Hi>]", format!("{:?}", [&v, &v]));
+
+    let alternate = DebugFn::new(|f: &mut Formatter<'_>| -> fmt::Result {
+        if f.alternate() {
+            f.write_str("pretty")
+        } else {
+            f.write_str("compact")
+        }
+    });
+
+    assert_eq!("compact", format!("{:?}", alternate));
+    assert_eq!("pretty", format!("{:#?}", alternate));
+}
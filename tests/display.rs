@@ -0,0 +1,23 @@
+use std::net::Ipv4Addr;
+
+use debug_helper::{DebugAsDisplay, DisplayAsDebug};
+
+#[test]
+fn display_as_debug() {
+    assert_eq!("Hi", format!("{:?}", DisplayAsDebug("Hi")));
+    assert_eq!("Hi", format!("{:#?}", DisplayAsDebug("Hi")));
+    assert_eq!("Hi   ", format!("{:5?}", DisplayAsDebug("Hi")));
+    assert_eq!("1.50", format!("{:.2?}", DisplayAsDebug(1.5)));
+    assert_eq!("[127.0.0.1]", format!("{:?}", [DisplayAsDebug(Ipv4Addr::LOCALHOST)]));
+    assert_eq!("Hi", format!("{}", DisplayAsDebug(&"Hi")));
+}
+
+#[test]
+fn debug_as_display() {
+    let v = vec![Some("a"), None];
+
+    assert_eq!("[Some(\"a\"), None]", format!("{}", DebugAsDisplay(&v)));
+    assert_eq!("[Some(\"a\"), None]", format!("{:#}", DebugAsDisplay(&v)));
+    assert_eq!(format!("{:#?}", v), format!("{:#?}", DebugAsDisplay(&v)));
+    assert_eq!("\"Hi\"", DebugAsDisplay("Hi").to_string());
+}
@@ -597,3 +597,407 @@ fn reset() {
         format!("{:#<#10.2?}", outer_3)
     );
 }
+
+#[test]
+#[allow(dead_code)]
+fn fake_variants() {
+    #[derive(Debug)]
+    struct Inner {
+        f1: u8,
+        f2: u8,
+    }
+
+    enum Outer {
+        F0,
+        F1(f64, Inner),
+        F2 { f1: f64, f2: Inner },
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_enum!(Outer::{F0, {F1(f1, f2): (let .f1 = f1, .f2)}, (F2{f1, f2}: (.f1, let .1 = f2))}, f, self);
+        }
+    }
+
+    let outer_1 = Outer::F0;
+    let outer_2 = Outer::F1(1.23456789, Inner {
+        f1: 5, f2: 10
+    });
+    let outer_3 = Outer::F2 {
+        f1: 1.23456789,
+        f2: Inner {
+            f1: 5, f2: 10
+        },
+    };
+
+    assert_eq!("F0", format!("{:?}", outer_1));
+    assert_eq!("F0", format!("{:#?}", outer_1));
+
+    assert_eq!("F1 { f1: 1.23456789, f2: Inner { f1: 5, f2: 10 } }", format!("{:?}", outer_2));
+    assert_eq!(
+        "F1 {\n    f1: 1.23456789,\n    f2: Inner {\n        f1: 5,\n        f2: 10,\n    },\n}",
+        format!("{:#?}", outer_2)
+    );
+    assert_eq!(
+        "F1 { f1: 0000001.23, f2: Inner { f1: 0000000005, f2: 0000000010 } }",
+        format!("{:010.2?}", outer_2)
+    );
+
+    assert_eq!("F2(1.23456789, Inner { f1: 5, f2: 10 })", format!("{:?}", outer_3));
+    assert_eq!(
+        "F2(\n    1.23456789,\n    Inner {\n        f1: 5,\n        f2: 10,\n    },\n)",
+        format!("{:#?}", outer_3)
+    );
+    assert_eq!(
+        "F2(0000001.23, Inner { f1: 0000000005, f2: 0000000010 })",
+        format!("{:010.2?}", outer_3)
+    );
+}
+
+#[test]
+#[allow(dead_code)]
+fn renamed_variants() {
+    #[derive(Debug)]
+    struct Inner {
+        f1: u8,
+        f2: u8,
+    }
+
+    enum Outer {
+        F0,
+        F1(f64, Inner),
+        F2 { f1: f64, f2: Inner },
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_enum!(Outer::{F0 as A0, (F1(f1, f2) as "A1": (.f1, .f2)), {F2{f1, f2} as A2: (.f1, .f2)}}, f, self);
+        }
+    }
+
+    let outer_1 = Outer::F0;
+    let outer_2 = Outer::F1(1.23456789, Inner {
+        f1: 5, f2: 10
+    });
+    let outer_3 = Outer::F2 {
+        f1: 1.23456789,
+        f2: Inner {
+            f1: 5, f2: 10
+        },
+    };
+
+    assert_eq!("A0", format!("{:?}", outer_1));
+    assert_eq!("A0", format!("{:#?}", outer_1));
+
+    assert_eq!("A1(1.23456789, Inner { f1: 5, f2: 10 })", format!("{:?}", outer_2));
+    assert_eq!(
+        "A1(\n    1.23456789,\n    Inner {\n        f1: 5,\n        f2: 10,\n    },\n)",
+        format!("{:#?}", outer_2)
+    );
+
+    assert_eq!("A2 { f1: 1.23456789, f2: Inner { f1: 5, f2: 10 } }", format!("{:?}", outer_3));
+    assert_eq!(
+        "A2 {\n    f1: 1.23456789,\n    f2: Inner {\n        f1: 5,\n        f2: 10,\n    },\n}",
+        format!("{:#?}", outer_3)
+    );
+}
+
+#[test]
+#[allow(dead_code)]
+fn non_exhaustive() {
+    enum Outer {
+        F0,
+        F1(u8, u8),
+        F2 { f1: u8, f2: u8 },
+        F3 { f1: u8, f2: u8 },
+        F4 { f1: u8, f2: u8 },
+        F5 { f1: u8, f2: u8 },
+        F6 { f1: u8, f2: u8 },
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_enum!(Outer::{F0, {F1(f1, _): (.f1, ..?)}, {F2{f1, ..}: (.f1, ..?)}, {F3{f1, f2}: (.f1, .f2, ..?)}, {F4{f1, f2: _}: (.f1, ..)}, {F5{f1, f2: _second}: (.f1, ..?)}, {F6{f1, f2}: (.f1, let .f2 = f2 * 2, ..?)}}, f, self);
+        }
+    }
+
+    let outer_1 = Outer::F0;
+    let outer_2 = Outer::F1(1, 2);
+    let outer_3 = Outer::F2 {
+        f1: 1, f2: 2
+    };
+    let outer_4 = Outer::F3 {
+        f1: 1, f2: 2
+    };
+    let outer_5 = Outer::F4 {
+        f1: 1, f2: 2
+    };
+
+    assert_eq!("F0", format!("{:?}", outer_1));
+    assert_eq!("F1 { f1: 1, .. }", format!("{:?}", outer_2));
+    assert_eq!("F1 {\n    f1: 1,\n    ..\n}", format!("{:#?}", outer_2));
+    assert_eq!("F2 { f1: 1, .. }", format!("{:?}", outer_3));
+    assert_eq!("F3 { f1: 1, f2: 2 }", format!("{:?}", outer_4));
+    assert_eq!("F3 {\n    f1: 1,\n    f2: 2,\n}", format!("{:#?}", outer_4));
+    assert_eq!("F4 { f1: 1, .. }", format!("{:?}", outer_5));
+    assert_eq!("F4 {\n    f1: 1,\n    ..\n}", format!("{:#?}", outer_5));
+    assert_eq!("F5 { f1: 1, .. }", format!("{:?}", Outer::F5 {
+        f1: 1, f2: 2
+    }));
+    assert_eq!("F6 { f1: 1, f2: 4 }", format!("{:?}", Outer::F6 {
+        f1: 1, f2: 2
+    }));
+}
+
+#[test]
+fn redacted() {
+    enum Outer {
+        F1(&'static str, String),
+        F2 { user: &'static str, password: String },
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_enum!(Outer::{(F1(user, password): (.user, .password: redacted(len))), {F2{user, password}: (.user, .password: redacted)}}, f, self);
+        }
+    }
+
+    let outer_1 = Outer::F1("magiclen", String::from("password"));
+    let outer_2 = Outer::F2 {
+        user: "magiclen", password: String::from("password")
+    };
+
+    assert_eq!("F1(\"magiclen\", <redacted 8 bytes>)", format!("{:?}", outer_1));
+    assert_eq!("F1(\n    \"magiclen\",\n    <redacted 8 bytes>,\n)", format!("{:#?}", outer_1));
+    assert_eq!("F2 { user: \"magiclen\", password: <redacted> }", format!("{:?}", outer_2));
+    assert_eq!(
+        "F2 {\n    user: \"magiclen\",\n    password: <redacted>,\n}",
+        format!("{:#?}", outer_2)
+    );
+}
+
+#[test]
+fn conditional_fields() {
+    enum Outer {
+        F1(u8, Option<u8>),
+        F2 { f1: Vec<u8>, f2: u32 },
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_enum!(Outer::{(F1(f1, f2): (.f1 if *f1 > 1, .f2 unless none)), {F2{f1, f2}: (.f1 unless empty, .f2 unless default)}}, f, self);
+        }
+    }
+
+    let outer_1 = Outer::F1(1, None);
+    let outer_2 = Outer::F1(2, Some(1));
+    let outer_3 = Outer::F2 {
+        f1: vec![], f2: 0
+    };
+    let outer_4 = Outer::F2 {
+        f1: vec![1], f2: 10
+    };
+
+    assert_eq!("F1", format!("{:?}", outer_1));
+    assert_eq!("F1(2, Some(1))", format!("{:?}", outer_2));
+    assert_eq!("F2", format!("{:?}", outer_3));
+    assert_eq!("F2 { f1: [1], f2: 10 }", format!("{:?}", outer_4));
+    assert_eq!("F2 {\n    f1: [\n        1,\n    ],\n    f2: 10,\n}", format!("{:#?}", outer_4));
+}
+
+#[test]
+fn flatten() {
+    struct Header {
+        id: u32,
+    }
+
+    debug_helper::derive_debug_fields_for_struct!(Header, self, .id);
+
+    enum Outer {
+        F1 { header: Header, body: u8 },
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_enum!(Outer::{{F1{header, body}: (.header: flatten, .body)}}, f, self);
+        }
+    }
+
+    let outer = Outer::F1 {
+        header: Header {
+            id: 1
+        },
+        body:   2,
+    };
+
+    assert_eq!("F1 { id: 1, body: 2 }", format!("{:?}", outer));
+    assert_eq!("F1 {\n    id: 1,\n    body: 2,\n}", format!("{:#?}", outer));
+}
+
+#[test]
+fn truncated() {
+    enum Outer {
+        F1(Vec<u32>),
+        F2 { f1: Vec<u32> },
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_enum!(Outer::{(F1(f1): (.f1: truncate(2))), {F2{f1}: (.f1: truncate(2))}}, f, self);
+        }
+    }
+
+    let outer_1 = Outer::F1((0..5).collect());
+    let outer_2 = Outer::F2 {
+        f1: (0..5).collect()
+    };
+
+    assert_eq!("F1([0, 1, ... 3 more])", format!("{:?}", outer_1));
+    assert_eq!("F2 { f1: [0, 1, ... 3 more] }", format!("{:?}", outer_2));
+}
+
+#[test]
+fn radix() {
+    enum Outer {
+        F1(u8),
+        F2 { bytes: &'static [u8] },
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_enum!(Outer::{(F1(v): (.v: hex(2))), {F2{bytes}: (.bytes: upper_hex(2))}}, f, self);
+        }
+    }
+
+    let outer_1 = Outer::F1(10);
+    let outer_2 = Outer::F2 {
+        bytes: &[10, 255]
+    };
+
+    assert_eq!("F1(0x0a)", format!("{:?}", outer_1));
+    assert_eq!("F2 { bytes: [0x0A, 0xFF] }", format!("{:?}", outer_2));
+}
+
+#[test]
+fn display() {
+    enum Outer {
+        F1(&'static str),
+        F2 { f1: f64 },
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_enum!(Outer::{(F1(f1): (.f1: display)), {F2{f1}: (.f1: display)}}, f, self);
+        }
+    }
+
+    let outer_1 = Outer::F1("Hi");
+    let outer_2 = Outer::F2 {
+        f1: 1.5
+    };
+
+    assert_eq!("F1(Hi)", format!("{:?}", outer_1));
+    assert_eq!("F2 { f1: 1.50 }", format!("{:.2?}", outer_2));
+}
+
+#[test]
+fn closure_fields() {
+    enum Outer {
+        F1(u8),
+        F2 { f1: u8 },
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_enum!(Outer::{(F1(v): (let .percent = |f| write!(f, "{}%", v))), {F2{f1}: (.f1, let .percent = |f| write!(f, "{}%", f1))}}, f, self);
+        }
+    }
+
+    let outer_1 = Outer::F1(5);
+    let outer_2 = Outer::F2 {
+        f1: 5
+    };
+
+    assert_eq!("F1(5%)", format!("{:?}", outer_1));
+    assert_eq!("F2 { f1: 5, percent: 5% }", format!("{:?}", outer_2));
+}
+
+#[test]
+#[allow(dead_code)]
+fn std_mode() {
+    enum Outer {
+        F0,
+        F1(u8, u8, u8),
+        F2 { f1: u8, f2: u8, f3: u8 },
+        F3(u8),
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_enum!(Outer::{F0 as Zero, (F1(a, _, c)), {F2{f1, f2: second, ..}}, (F3(v): (let .double = v * 2))} as std, f, self);
+        }
+    }
+
+    assert_eq!("Zero", format!("{:?}", Outer::F0));
+    assert_eq!("F1(1, 3)", format!("{:?}", Outer::F1(1, 2, 3)));
+    assert_eq!(
+        "F2 { f1: 1, f2: 2 }",
+        format!("{:?}", Outer::F2 {
+            f1: 1, f2: 2, f3: 3
+        })
+    );
+    assert_eq!("F3(2)", format!("{:?}", Outer::F3(1)));
+    assert_eq!("F1(\n    1,\n    3,\n)", format!("{:#?}", Outer::F1(1, 2, 3)));
+}
+
+#[test]
+#[allow(dead_code)]
+fn renamed_fields() {
+    #[derive(Debug)]
+    struct Inner {
+        f1: u8,
+        f2: u8,
+    }
+
+    enum Outer {
+        F0,
+        F1(f64, Inner),
+        F2 { f1: f64, f2: Inner },
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_enum!(Outer::{F0, {F1(f1, f2): (.f1 as number, .f2 as "inner")}, {F2{f1, f2}: (.f1 as number, .f2 as "inner")}}, f, self);
+        }
+    }
+
+    let outer_2 = Outer::F1(1.23456789, Inner {
+        f1: 5, f2: 10
+    });
+    let outer_3 = Outer::F2 {
+        f1: 1.23456789,
+        f2: Inner {
+            f1: 5, f2: 10
+        },
+    };
+
+    assert_eq!(
+        "F1 { number: 1.23456789, inner: Inner { f1: 5, f2: 10 } }",
+        format!("{:?}", outer_2)
+    );
+    assert_eq!(
+        "F1 {\n    number: 1.23456789,\n    inner: Inner {\n        f1: 5,\n        f2: 10,\n    \
+         },\n}",
+        format!("{:#?}", outer_2)
+    );
+
+    assert_eq!(
+        "F2 { number: 1.23456789, inner: Inner { f1: 5, f2: 10 } }",
+        format!("{:?}", outer_3)
+    );
+    assert_eq!(
+        "F2 {\n    number: 1.23456789,\n    inner: Inner {\n        f1: 5,\n        f2: 10,\n    \
+         },\n}",
+        format!("{:#?}", outer_3)
+    );
+}
@@ -754,3 +754,308 @@ fn reset() {
     );
     assert_eq!("Outer::F2 {\n    f1: Inner {\n        f1: 5#########,\n        f2: 10########,\n    },\n    f2: 1.23######,\n}", format!("{:#<#10.2?}", outer_3));
 }
+
+#[test]
+#[allow(dead_code)]
+fn fake_variants() {
+    #[derive(Debug)]
+    struct Inner {
+        f1: u8,
+        f2: u8,
+    }
+
+    enum Outer {
+        F0,
+        F1(f64, Inner),
+        F2 { f1: f64, f2: Inner },
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_enum!({Outer::F0, {F1(f1, f2): (let .f1 = f1, .f2)}, (F2{f1, f2}: (.f1, let .1 = f2))}, f, self);
+        }
+    }
+
+    let outer_1 = Outer::F0;
+    let outer_2 = Outer::F1(1.23456789, Inner {
+        f1: 5, f2: 10
+    });
+    let outer_3 = Outer::F2 {
+        f1: 1.23456789,
+        f2: Inner {
+            f1: 5, f2: 10
+        },
+    };
+
+    assert_eq!("Outer::F0", format!("{:?}", outer_1));
+    assert_eq!("Outer::F0", format!("{:#?}", outer_1));
+
+    assert_eq!(
+        "Outer::F1 { f1: 1.23456789, f2: Inner { f1: 5, f2: 10 } }",
+        format!("{:?}", outer_2)
+    );
+    assert_eq!(
+        "Outer::F1 {\n    f1: 1.23456789,\n    f2: Inner {\n        f1: 5,\n        f2: 10,\n    \
+         },\n}",
+        format!("{:#?}", outer_2)
+    );
+
+    assert_eq!("Outer::F2(1.23456789, Inner { f1: 5, f2: 10 })", format!("{:?}", outer_3));
+    assert_eq!(
+        "Outer::F2(\n    1.23456789,\n    Inner {\n        f1: 5,\n        f2: 10,\n    },\n)",
+        format!("{:#?}", outer_3)
+    );
+}
+
+#[test]
+#[allow(dead_code)]
+fn renamed_variants() {
+    #[derive(Debug)]
+    struct Inner {
+        f1: u8,
+        f2: u8,
+    }
+
+    enum Outer {
+        F0,
+        F1(f64, Inner),
+        F2 { f1: f64, f2: Inner },
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_enum!({Outer::F0 as A0, (F1(f1, f2) as "A1": (.f1, .f2)), {F2{f1, f2} as A2: (.f1, .f2)}}, f, self);
+        }
+    }
+
+    let outer_1 = Outer::F0;
+    let outer_2 = Outer::F1(1.23456789, Inner {
+        f1: 5, f2: 10
+    });
+    let outer_3 = Outer::F2 {
+        f1: 1.23456789,
+        f2: Inner {
+            f1: 5, f2: 10
+        },
+    };
+
+    assert_eq!("Outer::A0", format!("{:?}", outer_1));
+    assert_eq!("Outer::A0", format!("{:#?}", outer_1));
+
+    assert_eq!("Outer::A1(1.23456789, Inner { f1: 5, f2: 10 })", format!("{:?}", outer_2));
+    assert_eq!(
+        "Outer::A1(\n    1.23456789,\n    Inner {\n        f1: 5,\n        f2: 10,\n    },\n)",
+        format!("{:#?}", outer_2)
+    );
+
+    assert_eq!(
+        "Outer::A2 { f1: 1.23456789, f2: Inner { f1: 5, f2: 10 } }",
+        format!("{:?}", outer_3)
+    );
+    assert_eq!(
+        "Outer::A2 {\n    f1: 1.23456789,\n    f2: Inner {\n        f1: 5,\n        f2: 10,\n    \
+         },\n}",
+        format!("{:#?}", outer_3)
+    );
+}
+
+#[test]
+#[allow(dead_code)]
+fn renamed_fields() {
+    #[derive(Debug)]
+    struct Inner {
+        f1: u8,
+        f2: u8,
+    }
+
+    enum Outer {
+        F0,
+        F1(f64, Inner),
+        F2 { f1: f64, f2: Inner },
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_enum!({Outer::F0, {F1(f1, f2): (.f1 as number, .f2 as "inner")}, {F2{f1, f2}: (.f1 as number, .f2 as "inner")}}, f, self);
+        }
+    }
+
+    let outer_2 = Outer::F1(1.23456789, Inner {
+        f1: 5, f2: 10
+    });
+    let outer_3 = Outer::F2 {
+        f1: 1.23456789,
+        f2: Inner {
+            f1: 5, f2: 10
+        },
+    };
+
+    assert_eq!(
+        "Outer::F1 { number: 1.23456789, inner: Inner { f1: 5, f2: 10 } }",
+        format!("{:?}", outer_2)
+    );
+    assert_eq!(
+        "Outer::F1 {\n    number: 1.23456789,\n    inner: Inner {\n        f1: 5,\n        f2: \
+         10,\n    },\n}",
+        format!("{:#?}", outer_2)
+    );
+
+    assert_eq!(
+        "Outer::F2 { number: 1.23456789, inner: Inner { f1: 5, f2: 10 } }",
+        format!("{:?}", outer_3)
+    );
+    assert_eq!(
+        "Outer::F2 {\n    number: 1.23456789,\n    inner: Inner {\n        f1: 5,\n        f2: \
+         10,\n    },\n}",
+        format!("{:#?}", outer_3)
+    );
+}
+
+#[test]
+#[allow(dead_code)]
+fn non_exhaustive() {
+    enum Outer {
+        F0,
+        F1(u8, u8),
+        F2 { f1: u8, f2: u8 },
+        F3 { f1: u8, f2: u8 },
+        F4 { f1: u8, f2: u8 },
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_enum!({Outer::F0, {F1(f1, _): (.f1, ..?)}, {F2{f1, ..}: (.f1, ..?)}, {F3{f1, f2}: (.f1, .f2, ..?)}, {F4{f1, f2: _}: (.f1, ..)}}, f, self);
+        }
+    }
+
+    let outer_1 = Outer::F0;
+    let outer_2 = Outer::F1(1, 2);
+    let outer_3 = Outer::F2 {
+        f1: 1, f2: 2
+    };
+    let outer_4 = Outer::F3 {
+        f1: 1, f2: 2
+    };
+    let outer_5 = Outer::F4 {
+        f1: 1, f2: 2
+    };
+
+    assert_eq!("Outer::F0", format!("{:?}", outer_1));
+    assert_eq!("Outer::F1 { f1: 1, .. }", format!("{:?}", outer_2));
+    assert_eq!("Outer::F1 {\n    f1: 1,\n    ..\n}", format!("{:#?}", outer_2));
+    assert_eq!("Outer::F2 { f1: 1, .. }", format!("{:?}", outer_3));
+    assert_eq!("Outer::F3 { f1: 1, f2: 2 }", format!("{:?}", outer_4));
+    assert_eq!("Outer::F3 {\n    f1: 1,\n    f2: 2,\n}", format!("{:#?}", outer_4));
+    assert_eq!("Outer::F4 { f1: 1, .. }", format!("{:?}", outer_5));
+    assert_eq!("Outer::F4 {\n    f1: 1,\n    ..\n}", format!("{:#?}", outer_5));
+}
+
+#[test]
+fn name_modes() {
+    mod shapes {
+        use std::fmt::{self, Debug, Formatter};
+
+        pub enum Shape {
+            Point,
+            Circle(u8),
+            Rect { w: u8, h: u8 },
+        }
+
+        impl Debug for Shape {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+                match f.precision() {
+                    Some(1) => {
+                        debug_helper::impl_debug_for_enum!(Shape::{Point, (Circle(r): (.r)), {Rect{w, h}: (.w, .h)}} as name, f, self)
+                    },
+                    Some(2) => {
+                        debug_helper::impl_debug_for_enum!(Shape::{Point, (Circle(r): (.r)), {Rect{w, h}: (.w, .h)}} as path, f, self)
+                    },
+                    Some(3) => {
+                        debug_helper::impl_debug_for_enum!(Shape::{Point, (Circle(r): (.r)), {Rect{w, h}: (.w, .h)}} as "geo::Shape", f, self)
+                    },
+                    Some(4) => {
+                        debug_helper::impl_debug_for_enum!(Shape::{Point, (Circle(r): (.r)), {Rect{w, h}: (.w, .h)}} as Self, f, self)
+                    },
+                    _ => {
+                        debug_helper::impl_debug_for_enum!(Shape::{Point, (Circle(r): (.r)), {Rect{w, h}: (.w, .h)}}, f, self)
+                    },
+                }
+            }
+        }
+    }
+
+    use shapes::Shape;
+
+    let shapes = [Shape::Point, Shape::Circle(3), Shape::Rect {
+        w: 1, h: 2
+    }];
+
+    let expected = [
+        ["Point", "Circle(3)", "Rect { w: 1, h: 2 }"],
+        ["Shape::Point", "Shape::Circle(3)", "Shape::Rect { w: 1, h: 2 }"],
+        [
+            "enum_with_name::shapes::Shape::Point",
+            "enum_with_name::shapes::Shape::Circle(3)",
+            "enum_with_name::shapes::Shape::Rect { w: 1, h: 2 }",
+        ],
+        ["geo::Shape::Point", "geo::Shape::Circle(3)", "geo::Shape::Rect { w: 1, h: 2 }"],
+        ["Shape::Point", "Shape::Circle(3)", "Shape::Rect { w: 1, h: 2 }"],
+    ];
+
+    for (precision, expected) in expected.iter().enumerate() {
+        for (shape, expected) in shapes.iter().zip(expected.iter()) {
+            assert_eq!(*expected, format!("{:.1$?}", shape, precision));
+        }
+    }
+
+    assert_eq!("Shape::Rect {\n    w: 1,\n    h: 2,\n}", format!("{:#.1?}", shapes[2]));
+    assert_eq!("geo::Shape::Point", format!("{:#.3?}", shapes[0]));
+}
+
+#[test]
+#[allow(dead_code)]
+fn generic() {
+    mod inner {
+        pub enum Outer<'a, T> {
+            F0,
+            F1(&'a T),
+            F2 { f1: &'a T },
+        }
+    }
+
+    use inner::Outer;
+
+    impl<'a, T: Debug> Debug for Outer<'a, T> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            match f.precision() {
+                Some(1) => {
+                    debug_helper::impl_debug_for_enum!({Self::F0, (F1(f1): (.f1)), {F2{f1}: (.f1)}}, f, self);
+                },
+                Some(2) => {
+                    debug_helper::impl_debug_for_enum!({inner::Outer<'a, T>::F0, (F1(f1): (.f1)), {F2{f1}: (.f1)}}, f, self);
+                },
+                _ => {
+                    debug_helper::impl_debug_for_enum!(inner::Outer<'a, T>::{F0, (F1(f1): (.f1)), {F2{f1}: (.f1)}}, f, self);
+                },
+            }
+        }
+    }
+
+    let outer_1 = Outer::<u8>::F0;
+    let outer_2 = Outer::F1(&5u8);
+    let outer_3 = Outer::F2 {
+        f1: &vec![5u8]
+    };
+
+    assert_eq!("F0", format!("{:?}", outer_1));
+    assert_eq!("Outer<u8>::F0", format!("{:.1?}", outer_1));
+    assert_eq!("inner::Outer::F0", format!("{:.2?}", outer_1));
+
+    assert_eq!("F1(5)", format!("{:?}", outer_2));
+    assert_eq!("Outer<u8>::F1(5)", format!("{:.1?}", outer_2));
+    assert_eq!("inner::Outer::F1(5)", format!("{:.2?}", outer_2));
+
+    assert_eq!("F2 { f1: [5] }", format!("{:?}", outer_3));
+    assert_eq!("Outer<Vec<u8>>::F2 { f1: [5] }", format!("{:.1?}", outer_3));
+    assert_eq!("inner::Outer::F2 { f1: [5] }", format!("{:.2?}", outer_3));
+}
@@ -0,0 +1,88 @@
+use std::fmt::{self, Debug, Formatter};
+
+#[test]
+fn structure() {
+    struct Outer {
+        f1:      f64,
+        f2:      u8,
+        verbose: bool,
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            if self.verbose {
+                debug_helper::fmt_struct!(Outer, f, self, .f1, .f2)?;
+            } else {
+                debug_helper::fmt_struct!(Outer, f, self)?;
+            }
+
+            f.write_str(";")
+        }
+    }
+
+    let outer_1 = Outer {
+        f1: 1.23456789, f2: 5, verbose: true
+    };
+    let outer_2 = Outer {
+        f1: 1.23456789, f2: 5, verbose: false
+    };
+
+    assert_eq!("Outer { f1: 1.23456789, f2: 5 };", format!("{:?}", outer_1));
+    assert_eq!("Outer {\n    f1: 1.23456789,\n    f2: 5,\n};", format!("{:#?}", outer_1));
+    assert_eq!("Outer;", format!("{:?}", outer_2));
+    assert_eq!("Outer;", format!("{:#?}", outer_2));
+}
+
+#[test]
+fn tuple_structure() {
+    struct Outer(f64, u8, bool);
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            let result = if self.2 {
+                debug_helper::fmt_tuple_struct!(Outer, f, self, .0, .1)
+            } else {
+                debug_helper::fmt_tuple_struct!(Outer, f, self)
+            };
+
+            result.and_then(|_| f.write_str(";"))
+        }
+    }
+
+    let outer_1 = Outer(1.23456789, 5, true);
+    let outer_2 = Outer(1.23456789, 5, false);
+
+    assert_eq!("Outer(1.23456789, 5);", format!("{:?}", outer_1));
+    assert_eq!("Outer(\n    1.23456789,\n    5,\n);", format!("{:#?}", outer_1));
+    assert_eq!("Outer;", format!("{:?}", outer_2));
+    assert_eq!("Outer;", format!("{:#?}", outer_2));
+}
+
+#[test]
+#[allow(dead_code)]
+fn enumeration() {
+    enum Outer {
+        F0,
+        F1(f64, u8),
+        F2 { f1: f64, f2: u8 },
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            f.write_str("<")?;
+            debug_helper::fmt_enum!(Outer::{F0, (F1(f1, f2): (.f1, .f2)), {F2{f1, f2}: (.f1, .f2)}}, f, self)?;
+            debug_helper::fmt_enum!({Outer::F0, (F1(..)), {F2{..}}}, f, self)?;
+            f.write_str(">")
+        }
+    }
+
+    let outer_1 = Outer::F0;
+    let outer_2 = Outer::F1(1.23456789, 5);
+    let outer_3 = Outer::F2 {
+        f1: 1.23456789, f2: 5
+    };
+
+    assert_eq!("<F0Outer::F0>", format!("{:?}", outer_1));
+    assert_eq!("<F1(1.23456789, 5)Outer::F1>", format!("{:?}", outer_2));
+    assert_eq!("<F2 { f1: 1.23456789, f2: 5 }Outer::F2>", format!("{:?}", outer_3));
+}
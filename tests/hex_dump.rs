@@ -0,0 +1,37 @@
+use debug_helper::HexDump;
+
+#[test]
+fn compact() {
+    assert_eq!("[]", format!("{:?}", HexDump::new(b"")));
+    assert_eq!("[00017f80ff]", format!("{:?}", HexDump::new([0, 1, 127, 128, 255])));
+    assert_eq!("[6869]", format!("{:?}", HexDump::new(String::from("hi"))));
+}
+
+#[test]
+fn pretty() {
+    assert_eq!("[]", format!("{:#?}", HexDump::new(b"")));
+    assert_eq!(
+        "[\n    00000000: 00                                       .\n]",
+        format!("{:#?}", HexDump::new([0]))
+    );
+    assert_eq!(
+        "[\n    00000000: 3031 3233 3435 3637 3839 6162 6364 6566  0123456789abcdef\n]",
+        format!("{:#?}", HexDump::new(b"0123456789abcdef"))
+    );
+    assert_eq!(
+        "[\n    00000000: 3031 3233 3435 3637 3839 6162 6364 6566  0123456789abcdef\n    \
+         00000010: 0a7e 20                                  .~ \n]",
+        format!("{:#?}", HexDump::new(b"0123456789abcdef\n~ "))
+    );
+}
+
+#[test]
+fn nested() {
+    let v = vec![HexDump::new(vec![0u8; 17])];
+
+    assert_eq!(
+        "[\n    [\n        00000000: 0000 0000 0000 0000 0000 0000 0000 0000  ................\n        \
+         00000010: 00                                       .\n    ],\n]",
+        format!("{:#?}", v)
+    );
+}
@@ -0,0 +1,161 @@
+use std::fmt::Debug;
+
+struct Item<T>(T);
+
+debug_helper::derive_debug_for_tuple_struct!(impl<T: Debug> Item<T> { Self, self, .0 });
+
+#[test]
+fn item() {
+    assert_eq!("Item<u8>(5)", format!("{:?}", Item(5u8)));
+}
+
+#[test]
+fn structure() {
+    struct Outer {
+        f1: f64,
+        f2: u8,
+    }
+
+    debug_helper::derive_debug_for_struct!(Outer, self, .f1, (.f2, "{:02}", self.f2));
+
+    struct Unit;
+
+    debug_helper::derive_debug_for_struct!(Unit);
+
+    let outer = Outer {
+        f1: 1.23456789, f2: 5
+    };
+
+    assert_eq!("Outer { f1: 1.23456789, f2: 05 }", format!("{:?}", outer));
+    assert_eq!("Outer {\n    f1: 1.23456789,\n    f2: 05,\n}", format!("{:#?}", outer));
+    assert_eq!("Unit", format!("{:?}", Unit));
+}
+
+#[test]
+fn structure_generic() {
+    struct Outer<'a, T, const N: usize>
+    where
+        T: Clone, {
+        f1: &'a [T; N],
+        f2: Vec<Vec<T>>,
+    }
+
+    debug_helper::derive_debug_for_struct! {
+        impl<'a, T: Debug + Into<Option<T>>, const N: usize> Outer<'a, T, N> where T: Clone {
+            "Wrapper", self, .f1, .f2
+        }
+    }
+
+    let outer = Outer {
+        f1: &[1u8, 2], f2: vec![vec![3u8]]
+    };
+
+    assert_eq!("Wrapper { f1: [1, 2], f2: [[3]] }", format!("{:?}", outer));
+}
+
+#[test]
+fn tuple_structure() {
+    struct Outer(f64, u8);
+
+    debug_helper::derive_debug_for_tuple_struct!(Outer, self, .0, .1);
+
+    struct Wrapper<'a, T>(&'a T);
+
+    debug_helper::derive_debug_for_tuple_struct! {
+        impl<'a, T> Wrapper<'a, T> where T: Debug {
+            Wrapper, self, .0
+        }
+    }
+
+    let outer = Outer(1.23456789, 5);
+
+    assert_eq!("Outer(1.23456789, 5)", format!("{:?}", outer));
+    assert_eq!("Outer(\n    1.23456789,\n    5,\n)", format!("{:#?}", outer));
+    assert_eq!("Wrapper(5)", format!("{:?}", Wrapper(&5)));
+}
+
+#[test]
+#[allow(dead_code)]
+fn enumeration() {
+    enum Outer {
+        F0,
+        F1(f64, u8),
+        F2 { f1: f64, f2: u8 },
+    }
+
+    debug_helper::derive_debug_for_enum!(Outer::{F0, (F1(f1, f2): (.f1, .f2)), {F2{f1, f2}: (.f1, .f2)}}, self);
+
+    enum Generic<T> {
+        F0,
+        F1(T),
+    }
+
+    debug_helper::derive_debug_for_enum! {
+        impl<T: Debug> Generic<T> {
+            {Generic::F0, (F1(v): (.v))}, self
+        }
+    }
+
+    assert_eq!("F0", format!("{:?}", Outer::F0));
+    assert_eq!("F1(1.23456789, 5)", format!("{:?}", Outer::F1(1.23456789, 5)));
+    assert_eq!(
+        "F2 { f1: 1.23456789, f2: 5 }",
+        format!("{:?}", Outer::F2 {
+            f1: 1.23456789, f2: 5
+        })
+    );
+
+    enum Named {
+        F0,
+        F1(u8),
+    }
+
+    debug_helper::derive_debug_for_enum!(Named::{F0, (F1(v): (.v))} as name, self);
+
+    enum NamedGeneric<T> {
+        F0,
+        F1(T),
+    }
+
+    debug_helper::derive_debug_for_enum! {
+        impl<T: Debug> NamedGeneric<T> {
+            NamedGeneric::{F0, (F1(v): (.v))} as Self, self
+        }
+    }
+
+    assert_eq!("Generic::F0", format!("{:?}", Generic::<u8>::F0));
+    assert_eq!("Generic::F1(\n    5,\n)", format!("{:#?}", Generic::F1(5)));
+
+    assert_eq!("Named::F0", format!("{:?}", Named::F0));
+    assert_eq!("Named::F1(5)", format!("{:?}", Named::F1(5)));
+    assert_eq!("NamedGeneric<u8>::F0", format!("{:?}", NamedGeneric::<u8>::F0));
+    assert_eq!("NamedGeneric<u8>::F1(5)", format!("{:?}", NamedGeneric::F1(5u8)));
+}
+
+#[test]
+fn fields() {
+    struct Header<T> {
+        id:    T,
+        extra: u8,
+    }
+
+    debug_helper::derive_debug_fields_for_struct! {
+        impl<T: Debug> Header<T> {
+            self, .id, let .len = 1, (.extra, "{:02}", self.extra)
+        }
+    }
+
+    struct Outer {
+        header: Header<u16>,
+    }
+
+    debug_helper::derive_debug_for_struct!(Outer, self, .header: flatten);
+
+    let outer = Outer {
+        header: Header {
+            id: 5, extra: 3
+        },
+    };
+
+    assert_eq!("Outer { id: 5, len: 1, extra: 03 }", format!("{:?}", outer));
+}
@@ -0,0 +1,46 @@
+use debug_helper::Redacted;
+
+#[test]
+fn placeholder() {
+    let password = Redacted::new(String::from("password"));
+
+    assert_eq!("<redacted>", format!("{:?}", password));
+    assert_eq!("<redacted>", format!("{:#?}", password));
+    assert_eq!("password", password.as_str());
+    assert_eq!("password", password.into_inner());
+
+    assert_eq!("***", format!("{:?}", Redacted::with_placeholder(1, "***")));
+}
+
+#[test]
+fn len() {
+    assert_eq!("<redacted 0 bytes>", format!("{:?}", Redacted::with_len("")));
+    assert_eq!("<redacted 8 bytes>", format!("{:?}", Redacted::with_len(b"password")));
+    assert_eq!("<redacted 3 bytes>", format!("{:?}", Redacted::with_len(vec![1u8, 2, 3])));
+}
+
+#[test]
+fn hash() {
+    assert_eq!("<redacted #CBF29CE484222325>", format!("{:?}", Redacted::with_hash("")));
+    assert_eq!("<redacted #4B1A493507B3A318>", format!("{:?}", Redacted::with_hash("password")));
+    assert_eq!(
+        format!("{:?}", Redacted::with_hash(String::from("password"))),
+        format!("{:?}", Redacted::with_hash(b"password"))
+    );
+}
+
+#[test]
+fn in_struct() {
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Login {
+        user:     &'static str,
+        password: Redacted<&'static str>,
+    }
+
+    let login = Login {
+        user: "magiclen", password: Redacted::new("password")
+    };
+
+    assert_eq!("Login { user: \"magiclen\", password: <redacted> }", format!("{:?}", login));
+}
@@ -0,0 +1,210 @@
+#![allow(dead_code)]
+
+use std::fmt::{self, Debug, Formatter};
+
+macro_rules! assert_same {
+    ($expected:expr, $actual:expr) => {{
+        let (expected, actual) = (&$expected, &$actual);
+
+        assert_eq!(format!("{:?}", expected), format!("{:?}", actual));
+        assert_eq!(format!("{:#?}", expected), format!("{:#?}", actual));
+        assert_eq!(format!("{:10?}", expected), format!("{:10?}", actual));
+        assert_eq!(format!("{:<#10.1?}", expected), format!("{:<#10.1?}", actual));
+        assert_eq!(format!("{:+?}", expected), format!("{:+?}", actual));
+    }};
+}
+
+mod derived {
+    #[derive(Debug)]
+    pub struct Inner {
+        pub f1: u8,
+        pub f2: Option<&'static str>,
+    }
+
+    #[derive(Debug)]
+    pub enum Mixed {
+        Unit,
+        EmptyTuple(),
+        EmptyStruct {},
+        Tuple(f64, Inner),
+        Struct { f1: i8, f2: Vec<Inner> },
+    }
+
+    #[derive(Debug)]
+    pub struct Unit;
+
+    #[derive(Debug)]
+    pub struct EmptyStruct {}
+
+    #[derive(Debug)]
+    pub struct EmptyTuple();
+
+    #[derive(Debug)]
+    pub struct Named {
+        pub f1: f64,
+        pub f2: Mixed,
+    }
+
+    #[derive(Debug)]
+    pub struct Tuple(pub i8, pub Inner);
+}
+
+mod helped {
+    use super::*;
+
+    pub struct Inner {
+        pub f1: u8,
+        pub f2: Option<&'static str>,
+    }
+
+    debug_helper::derive_debug_for_struct!(Inner, self, .f1, .f2);
+
+    pub enum Mixed {
+        Unit,
+        EmptyTuple(),
+        EmptyStruct {},
+        Tuple(f64, Inner),
+        Struct { f1: i8, f2: Vec<Inner> },
+    }
+
+    impl Debug for Mixed {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_enum!(Mixed::{Unit, (EmptyTuple()), {EmptyStruct{}}, (Tuple(f1, f2): (.f1, .f2)), {Struct{f1, f2}: (.f1, .f2)}}, f, self);
+        }
+    }
+
+    pub enum StdMixed {
+        Unit,
+        EmptyTuple(),
+        EmptyStruct {},
+        Tuple(f64, Inner),
+        Struct { f1: i8, f2: Vec<Inner> },
+    }
+
+    debug_helper::derive_debug_for_enum!(StdMixed::{Unit, (EmptyTuple()), {EmptyStruct{}}, (Tuple(v1, v2)), {Struct{f1, f2: inner}}} as std, self);
+
+    pub struct Unit;
+
+    impl Debug for Unit {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(Unit, f, self);
+        }
+    }
+
+    pub struct EmptyStruct {}
+
+    debug_helper::derive_debug_for_struct!(EmptyStruct, self);
+
+    pub struct EmptyTuple();
+
+    impl Debug for EmptyTuple {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::fmt_tuple_struct!(EmptyTuple, f, self)
+        }
+    }
+
+    pub struct Named {
+        pub f1: f64,
+        pub f2: Mixed,
+    }
+
+    impl Debug for Named {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::fmt_struct!(Named, f, self, .f1, .f2)
+        }
+    }
+
+    pub struct Tuple(pub i8, pub Inner);
+
+    impl Debug for Tuple {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_tuple_struct!(Tuple, f, self, .0, .1);
+        }
+    }
+}
+
+macro_rules! mixed {
+    ($module:ident:: $ty:ident) => {
+        [
+            $module::$ty::Unit,
+            $module::$ty::EmptyTuple(),
+            $module::$ty::EmptyStruct {},
+            $module::$ty::Tuple(1.23456, $module::Inner {
+                f1: 1, f2: Some("Hi")
+            }),
+            $module::$ty::Struct {
+                f1: -1,
+                f2: vec![$module::Inner {
+                    f1: 2, f2: None
+                }],
+            },
+        ]
+    };
+}
+
+#[test]
+fn enumeration() {
+    for (expected, actual) in mixed!(derived::Mixed).iter().zip(mixed!(helped::Mixed).iter()) {
+        assert_same!(expected, actual);
+    }
+}
+
+#[test]
+fn enumeration_std_mode() {
+    for (expected, actual) in mixed!(derived::Mixed).iter().zip(mixed!(helped::StdMixed).iter()) {
+        assert_same!(expected, actual);
+    }
+}
+
+#[test]
+fn structure() {
+    assert_same!(derived::Unit, helped::Unit);
+    assert_same!(derived::EmptyStruct {}, helped::EmptyStruct {});
+    assert_same!(derived::EmptyTuple(), helped::EmptyTuple());
+
+    for (expected, actual) in mixed!(derived::Mixed).into_iter().zip(mixed!(helped::Mixed)) {
+        assert_same!(
+            derived::Named {
+                f1: -0.5, f2: expected
+            },
+            helped::Named {
+                f1: -0.5, f2: actual
+            }
+        );
+    }
+
+    assert_same!(
+        derived::Tuple(-3, derived::Inner {
+            f1: 3, f2: Some("Hello")
+        }),
+        helped::Tuple(-3, helped::Inner {
+            f1: 3, f2: Some("Hello")
+        })
+    );
+}
+
+#[test]
+fn std_mode_ref_bindings() {
+    #[derive(Debug)]
+    enum Derived {
+        Tuple(u8, &'static str),
+        Struct { f1: u8, f2: &'static str },
+    }
+
+    enum Helped {
+        Tuple(u8, &'static str),
+        Struct { f1: u8, f2: &'static str },
+    }
+
+    debug_helper::derive_debug_for_enum!(Helped::{(Tuple(ref v1, v2)), {Struct{ref f1, f2: ref inner}}} as std, self);
+
+    assert_same!(Derived::Tuple(1, "Hi"), Helped::Tuple(1, "Hi"));
+    assert_same!(
+        Derived::Struct {
+            f1: 1, f2: "Hi"
+        },
+        Helped::Struct {
+            f1: 1, f2: "Hi"
+        }
+    );
+}
@@ -280,6 +280,78 @@ fn custom_fmt() {
     );
 }
 
+#[test]
+fn custom_fmt_multiline() {
+    struct Outer {
+        f1: &'static str,
+        f2: &'static str,
+        f3: &'static str,
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(Outer, f, self, (.f1, "{}", self.f1), (.f2, "{}", self.f2), (.f3, "{}", self.f3));
+        }
+    }
+
+    let outer = Outer {
+        f1: "a\nb", f2: "c\r\nd\r\n", f3: "e\n\nf\n"
+    };
+
+    assert_eq!("Outer { f1: a\nb, f2: c\r\nd\r\n, f3: e\n\nf\n }", format!("{:?}", outer));
+    assert_eq!(
+        "Outer {\n    f1: a\n        b,\n    f2: c\r\n        d\r\n    ,\n    f3: e\n        \n        f\n    ,\n}",
+        format!("{:#?}", outer)
+    );
+}
+
+#[test]
+#[allow(dead_code)]
+fn custom_fmt_nested() {
+    use std::collections::BTreeMap;
+
+    struct Inner {
+        f1: u8,
+        f2: u8,
+    }
+
+    impl Debug for Inner {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(Inner, f, self, (.f1, "{}\n{}", self.f1, self.f2));
+        }
+    }
+
+    #[derive(Debug)]
+    struct Outer {
+        f1: Vec<Vec<Inner>>,
+        f2: BTreeMap<u8, Inner>,
+    }
+
+    let mut f2 = BTreeMap::new();
+
+    f2.insert(3, Inner {
+        f1: 3, f2: 4
+    });
+
+    let outer = Outer {
+        f1: vec![vec![Inner {
+            f1: 1, f2: 2
+        }]],
+        f2,
+    };
+
+    assert_eq!(
+        "Outer { f1: [[Inner { f1: 1\n2 }]], f2: {3: Inner { f1: 3\n4 }} }",
+        format!("{:?}", outer)
+    );
+    assert_eq!(
+        "Outer {\n    f1: [\n        [\n            Inner {\n                f1: 1\n                    2,\n            \
+         },\n        ],\n    ],\n    f2: {\n        3: Inner {\n            f1: 3\n                4,\n        \
+         },\n    },\n}",
+        format!("{:#?}", outer)
+    );
+}
+
 #[test]
 fn additional_fields() {
     #[allow(dead_code)]
@@ -365,3 +437,633 @@ fn fake_struct() {
     assert_eq!("Outer { f1: 1.23###### }", format!("{:#<10.2?}", outer));
     assert_eq!("Outer {\n    f1: 1.23######,\n}", format!("{:#<#10.2?}", outer));
 }
+
+#[test]
+#[allow(dead_code)]
+fn unit_renamed_literal_and_path() {
+    struct Outer {
+        f1: f64,
+    }
+
+    struct Outer2 {
+        f1: f64,
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!("FileHandle", f, self);
+        }
+    }
+
+    impl Debug for Outer2 {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(net::Socket, f, self);
+        }
+    }
+
+    let outer = Outer {
+        f1: 1.23456789
+    };
+    let outer_2 = Outer2 {
+        f1: 1.23456789
+    };
+
+    assert_eq!("FileHandle", format!("{:?}", outer));
+    assert_eq!("FileHandle", format!("{:#?}", outer));
+
+    assert_eq!("net::Socket", format!("{:?}", outer_2));
+    assert_eq!("net::Socket", format!("{:#?}", outer_2));
+}
+
+#[test]
+fn all_renamed_literal_and_path() {
+    struct Outer {
+        f1: f64,
+        f2: u8,
+    }
+
+    struct Outer2 {
+        f1: f64,
+        f2: u8,
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!("FileHandle", f, self, .f1, .f2);
+        }
+    }
+
+    impl Debug for Outer2 {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(net::Socket, f, self, .f1, .f2);
+        }
+    }
+
+    let outer = Outer {
+        f1: 1.23456789, f2: 5
+    };
+    let outer_2 = Outer2 {
+        f1: 1.23456789, f2: 5
+    };
+
+    assert_eq!("FileHandle { f1: 1.23456789, f2: 5 }", format!("{:?}", outer));
+    assert_eq!("FileHandle {\n    f1: 1.23456789,\n    f2: 5,\n}", format!("{:#?}", outer));
+    assert_eq!("FileHandle { f1: 0000001.23, f2: 0000000005 }", format!("{:010.2?}", outer));
+
+    assert_eq!("net::Socket { f1: 1.23456789, f2: 5 }", format!("{:?}", outer_2));
+    assert_eq!("net::Socket {\n    f1: 1.23456789,\n    f2: 5,\n}", format!("{:#?}", outer_2));
+    assert_eq!("net::Socket { f1: 0000001.23, f2: 0000000005 }", format!("{:010.2?}", outer_2));
+}
+
+#[test]
+fn renamed_fields() {
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    struct Inner {
+        f1: u8,
+        f2: u8,
+    }
+
+    struct Outer {
+        f1: f64,
+        f2: Inner,
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(Outer, f, self, .f1 as number, .f2 as "inner");
+        }
+    }
+
+    let outer = Outer {
+        f1: 1.23456789,
+        f2: Inner {
+            f1: 5, f2: 10
+        },
+    };
+
+    assert_eq!(
+        "Outer { number: 1.23456789, inner: Inner { f1: 5, f2: 10 } }",
+        format!("{:?}", outer)
+    );
+    assert_eq!(
+        "Outer {\n    number: 1.23456789,\n    inner: Inner {\n        f1: 5,\n        f2: 10,\n    },\n}",
+        format!("{:#?}", outer)
+    );
+    assert_eq!(
+        "Outer { number: 0000001.23, inner: Inner { f1: 0000000005, f2: 0000000010 } }",
+        format!("{:010.2?}", outer)
+    );
+}
+
+#[test]
+fn non_exhaustive() {
+    #[allow(dead_code)]
+    struct Outer {
+        f1: u8,
+        f2: u8,
+        f3: u8,
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(Outer, f, self, .f1, .f3 as number, ..);
+        }
+    }
+
+    let outer = Outer {
+        f1: 1, f2: 2, f3: 3
+    };
+
+    assert_eq!("Outer { f1: 1, number: 3, .. }", format!("{:?}", outer));
+    assert_eq!("Outer {\n    f1: 1,\n    number: 3,\n    ..\n}", format!("{:#?}", outer));
+    assert_eq!("Outer { f1: 0000000001, number: 0000000003, .. }", format!("{:010?}", outer));
+}
+
+#[test]
+fn non_exhaustive_without_fields() {
+    #[allow(dead_code)]
+    struct Outer {
+        f1: u8,
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(Outer, f, self, ..);
+        }
+    }
+
+    let outer = Outer {
+        f1: 1
+    };
+
+    assert_eq!("Outer { .. }", format!("{:?}", outer));
+    assert_eq!("Outer { .. }", format!("{:#?}", outer));
+}
+
+#[test]
+fn non_exhaustive_auto() {
+    struct Outer {
+        f1: u8,
+        f2: u8,
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            if f.alternate() {
+                debug_helper::impl_debug_for_struct!(Outer, f, self, .f1, .f2, ..?);
+            } else {
+                debug_helper::impl_debug_for_struct!(Outer, f, self, .f1, skip(.f2), ..?);
+            }
+        }
+    }
+
+    let outer = Outer {
+        f1: 1, f2: 2
+    };
+
+    assert_eq!("Outer { f1: 1, .. }", format!("{:?}", outer));
+    assert_eq!("Outer {\n    f1: 1,\n    f2: 2,\n}", format!("{:#?}", outer));
+}
+
+#[test]
+fn redacted() {
+    struct Outer {
+        user:     &'static str,
+        password: String,
+        token:    String,
+        key:      [u8; 32],
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(Outer, f, self, .user, .password: redacted, .token as "t": redacted("***"), .key: redacted(len));
+        }
+    }
+
+    let outer = Outer {
+        user:     "magiclen",
+        password: String::from("password"),
+        token:    String::from("token"),
+        key:      [0; 32],
+    };
+
+    assert_eq!(
+        "Outer { user: \"magiclen\", password: <redacted>, t: ***, key: <redacted 32 bytes> }",
+        format!("{:?}", outer)
+    );
+    assert_eq!(
+        "Outer {\n    user: \"magiclen\",\n    password: <redacted>,\n    t: ***,\n    key: \
+         <redacted 32 bytes>,\n}",
+        format!("{:#?}", outer)
+    );
+}
+
+#[test]
+fn conditional_fields() {
+    struct Outer {
+        f1: u8,
+        f2: Option<u8>,
+        f3: Vec<u8>,
+        f4: u32,
+        f5: String,
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(Outer, f, self, .f1 if self.f1 > 1, .f2 unless none, .f3 unless empty, .f4 as count unless default, (.f5, "{}", self.f5) unless empty);
+        }
+    }
+
+    let outer_1 = Outer {
+        f1: 1, f2: None, f3: vec![], f4: 0, f5: String::new()
+    };
+    let outer_2 = Outer {
+        f1: 2, f2: Some(1), f3: vec![1], f4: 10, f5: String::from("Hi")
+    };
+
+    assert_eq!("Outer", format!("{:?}", outer_1));
+    assert_eq!(
+        "Outer { f1: 2, f2: Some(1), f3: [1], count: 10, f5: Hi }",
+        format!("{:?}", outer_2)
+    );
+    assert_eq!(
+        "Outer {\n    f1: 2,\n    f2: Some(\n        1,\n    ),\n    f3: [\n        1,\n    ],\n    \
+         count: 10,\n    f5: Hi,\n}",
+        format!("{:#?}", outer_2)
+    );
+}
+
+#[test]
+fn conditional_fields_with_modifiers() {
+    struct Outer {
+        user:     &'static str,
+        password: String,
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(Outer, f, self, .user, .password as pwd: redacted(len) unless empty, ..);
+        }
+    }
+
+    let outer_1 = Outer {
+        user: "magiclen", password: String::new()
+    };
+    let outer_2 = Outer {
+        user: "magiclen", password: String::from("password")
+    };
+
+    assert_eq!("Outer { user: \"magiclen\", .. }", format!("{:?}", outer_1));
+    assert_eq!(
+        "Outer { user: \"magiclen\", pwd: <redacted 8 bytes>, .. }",
+        format!("{:?}", outer_2)
+    );
+}
+
+#[test]
+fn flatten() {
+    struct Header {
+        id:   u32,
+        kind: &'static str,
+    }
+
+    debug_helper::derive_debug_fields_for_struct!(Header, self, .id, .kind as "type");
+
+    struct Meta {
+        tags: Vec<&'static str>,
+    }
+
+    debug_helper::derive_debug_fields_for_struct!(Meta, self, .tags unless empty);
+
+    struct Outer {
+        header: Header,
+        meta:   Meta,
+        body:   u8,
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(Outer, f, self, .header: flatten, .meta: flatten, .body);
+        }
+    }
+
+    let mut outer = Outer {
+        header: Header {
+            id: 1, kind: "a"
+        },
+        meta:   Meta {
+            tags: vec![]
+        },
+        body:   2,
+    };
+
+    assert_eq!("Outer { id: 1, type: \"a\", body: 2 }", format!("{:?}", outer));
+    assert_eq!("Outer {\n    id: 1,\n    type: \"a\",\n    body: 2,\n}", format!("{:#?}", outer));
+
+    outer.meta.tags.push("b");
+
+    assert_eq!("Outer { id: 1, type: \"a\", tags: [\"b\"], body: 2 }", format!("{:?}", outer));
+}
+
+#[test]
+fn truncated() {
+    use std::collections::BTreeMap;
+
+    struct Outer {
+        f1: Vec<u32>,
+        f2: BTreeMap<u32, u32>,
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(Outer, f, self, .f1: truncate(3), .f2: truncate_map(1));
+        }
+    }
+
+    let outer = Outer {
+        f1: (0..10000).collect(), f2: (0..3).map(|i| (i, i * 2)).collect()
+    };
+
+    assert_eq!(
+        "Outer { f1: [0, 1, 2, ... 9,997 more], f2: {0: 0, ... 2 more} }",
+        format!("{:?}", outer)
+    );
+    assert_eq!(
+        "Outer {\n    f1: [\n        0,\n        1,\n        2,\n        ... 9,997 more,\n    \
+         ],\n    f2: {\n        0: 0,\n        ... 2 more,\n    },\n}",
+        format!("{:#?}", outer)
+    );
+}
+
+#[test]
+fn truncated_str_and_bytes() {
+    struct Outer {
+        name: String,
+        body: Vec<u8>,
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(Outer, f, self, .name: truncate_str(5), .body: truncate_bytes(4));
+        }
+    }
+
+    let outer = Outer {
+        name: String::from("Hello, world!"), body: b"GET / HTTP/1.1".to_vec()
+    };
+
+    assert_eq!(
+        "Outer { name: \"Hello\"... (13 bytes), body: b\"GET \"... (14 bytes) }",
+        format!("{:?}", outer)
+    );
+    assert_eq!(
+        "Outer {\n    name: \"Hello\"... (13 bytes),\n    body: b\"GET \"... (14 bytes),\n}",
+        format!("{:#?}", outer)
+    );
+}
+
+#[test]
+fn radix() {
+    struct Outer {
+        flags: u8,
+        id:    u32,
+        mode:  u16,
+        mask:  i8,
+        key:   [u8; 4],
+        data:  Vec<u16>,
+        words: &'static [u32],
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(Outer, f, self, .flags: binary(8), .id: upper_hex(8), .mode: octal, .mask: hex, .key: hex(2), .data: upper_hex, .words: hex(4));
+        }
+    }
+
+    let outer = Outer {
+        flags: 0b101,
+        id:    0xBEEF,
+        mode:  0o755,
+        mask:  -1,
+        key:   [0, 1, 0xAB, 0xFF],
+        data:  vec![0xA, 0x100],
+        words: &[0x1F],
+    };
+
+    assert_eq!(
+        "Outer { flags: 0b00000101, id: 0x0000BEEF, mode: 0o755, mask: 0xff, key: [0x00, 0x01, \
+         0xab, 0xff], data: [0xA, 0x100], words: [0x001f] }",
+        format!("{:?}", outer)
+    );
+    assert_eq!(
+        "Outer {\n    flags: 0b00000101,\n    id: 0x0000BEEF,\n    mode: 0o755,\n    mask: 0xff,\n    \
+         key: [\n        0x00,\n        0x01,\n        0xab,\n        0xff,\n    ],\n    data: [\n        \
+         0xA,\n        0x100,\n    ],\n    words: [\n        0x001f,\n    ],\n}",
+        format!("{:#?}", outer)
+    );
+}
+
+#[test]
+#[allow(dead_code)]
+fn hex_dump() {
+    #[derive(Debug)]
+    struct Packet {
+        id:      u8,
+        payload: Payload,
+    }
+
+    struct Payload {
+        bytes: Vec<u8>,
+    }
+
+    impl Debug for Payload {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(Payload, f, self, .bytes: hex_dump);
+        }
+    }
+
+    let packet = Packet {
+        id:      1,
+        payload: Payload {
+            bytes: b"GET / HTTP/1.1\r\nHost".to_vec()
+        },
+    };
+
+    assert_eq!(
+        "Packet { id: 1, payload: Payload { bytes: [474554202f20485454502f312e310d0a486f7374] } }",
+        format!("{:?}", packet)
+    );
+    assert_eq!(
+        concat!(
+            "Packet {\n",
+            "    id: 1,\n",
+            "    payload: Payload {\n",
+            "        bytes: [\n",
+            "            00000000: 4745 5420 2f20 4854 5450 2f31 2e31 0d0a  GET / HTTP/1.1..\n",
+            "            00000010: 486f 7374                                Host\n",
+            "        ],\n",
+            "    },\n",
+            "}",
+        ),
+        format!("{:#?}", packet)
+    );
+}
+
+#[test]
+fn display() {
+    use std::net::Ipv4Addr;
+
+    struct Outer {
+        ip:   Ipv4Addr,
+        name: &'static str,
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(Outer, f, self, .ip: display, .name: display);
+        }
+    }
+
+    let outer = Outer {
+        ip: Ipv4Addr::LOCALHOST, name: "localhost"
+    };
+
+    assert_eq!("Outer { ip: 127.0.0.1, name: localhost }", format!("{:?}", outer));
+    assert_eq!("Outer {\n    ip: 127.0.0.1,\n    name: localhost,\n}", format!("{:#?}", outer));
+}
+
+#[test]
+fn closure_fields() {
+    struct Outer {
+        f1: Vec<u8>,
+    }
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(Outer, f, self, let .sum = |f| write!(f, "{}", self.f1.iter().sum::<u8>()), let .nested = |f: &mut Formatter<'_>| f.debug_list().entries(&self.f1).finish());
+        }
+    }
+
+    let outer = Outer {
+        f1: vec![1, 2]
+    };
+
+    assert_eq!("Outer { sum: 3, nested: [1, 2] }", format!("{:?}", outer));
+    assert_eq!(
+        "Outer {\n    sum: 3,\n    nested: [\n        1,\n        2,\n    ],\n}",
+        format!("{:#?}", outer)
+    );
+}
+
+#[test]
+fn checked_fields() {
+    struct Outer<T> {
+        f1: u8,
+        f2: T,
+        f3: Option<u8>,
+        f4: &'static str,
+        f5: u8,
+    }
+
+    impl<T: Debug> Debug for Outer<T> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            if f.alternate() {
+                debug_helper::impl_debug_for_struct!("Renamed", f, self, .f1, skip(.f2, .f5), .f3 unless none, (.f4, "{}", self.f4), let .ghost = 1);
+            } else {
+                debug_helper::impl_debug_for_struct!(Outer<T>, f, self, .f1 as first, .f2, skip(.f3), skip(.f4), .f5: hex, ..);
+            }
+        }
+    }
+
+    struct Checked {
+        f1: u8,
+    }
+
+    debug_helper::derive_debug_for_struct!(Checked, self, .f1, skip());
+
+    let outer = Outer {
+        f1: 1, f2: "Hi", f3: None, f4: "Hello", f5: 255
+    };
+
+    assert_eq!("Outer { first: 1, f2: \"Hi\", f5: 0xff, .. }", format!("{:?}", outer));
+    assert_eq!("Renamed {\n    f1: 1,\n    f4: Hello,\n    ghost: 1,\n}", format!("{:#?}", outer));
+    assert_eq!(
+        "Checked { f1: 1 }",
+        format!("{:?}", Checked {
+            f1: 1
+        })
+    );
+}
+
+#[test]
+fn checked_fields_with_names_and_ghosts() {
+    mod net {
+        #[allow(dead_code)]
+        pub struct Socket;
+    }
+
+    struct Handle {
+        fd: i32,
+        x:  u8,
+    }
+
+    impl Debug for Handle {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            if f.alternate() {
+                debug_helper::impl_debug_for_struct!(net::Socket, f, self, .fd, skip(.x));
+            } else {
+                debug_helper::impl_debug_for_struct!(FileHandle, f, self, (.fd, "#{}", self.fd), let .sum = self.fd + self.x as i32, skip(.x));
+            }
+        }
+    }
+
+    let handle = Handle {
+        fd: 3, x: 4
+    };
+
+    assert_eq!("FileHandle { fd: #3, sum: 7 }", format!("{:?}", handle));
+    assert_eq!("net::Socket {\n    fd: 3,\n}", format!("{:#?}", handle));
+}
+
+#[test]
+fn generic() {
+    struct Outer<'a, T> {
+        f1: &'a T,
+    }
+
+    struct Unit<T>(Option<T>);
+
+    impl<'a, T: Debug> Debug for Outer<'a, T> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            if f.alternate() {
+                debug_helper::impl_debug_for_struct!(Self, f, self, .f1);
+            } else {
+                debug_helper::impl_debug_for_struct!(Outer<'a, T>, f, self, .f1);
+            }
+        }
+    }
+
+    impl<T> Debug for Unit<T> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_struct!(Self, f);
+        }
+    }
+
+    let outer_1 = Outer {
+        f1: &5u8
+    };
+    let outer_2 = Outer {
+        f1: &vec![Some(5u8)]
+    };
+
+    assert_eq!("Outer { f1: 5 }", format!("{:?}", outer_1));
+    assert_eq!("Outer<u8> {\n    f1: 5,\n}", format!("{:#?}", outer_1));
+    assert_eq!("Outer { f1: [Some(5)] }", format!("{:?}", outer_2));
+    assert_eq!(
+        "Outer<Vec<Option<u8>>> {\n    f1: [\n        Some(\n            5,\n        ),\n    ],\n}",
+        format!("{:#?}", outer_2)
+    );
+
+    assert_eq!("Unit<(u8, &str)>", format!("{:?}", Unit::<(u8, &str)>(None)));
+}
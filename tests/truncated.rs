@@ -0,0 +1,95 @@
+use std::collections::BTreeMap;
+
+use debug_helper::{Truncated, TruncatedBytes, TruncatedMap, TruncatedStr};
+
+#[test]
+fn list() {
+    let v: Vec<u32> = (0..10000).collect();
+
+    assert_eq!("[0, 1, 2, 3, 4, ... 9,995 more]", format!("{:?}", Truncated::new(&v, 5)));
+    assert_eq!(
+        "[\n    0,\n    1,\n    ... 9,998 more,\n]",
+        format!("{:#?}", Truncated::new(&v, 2))
+    );
+    assert_eq!("[00, 01, ... 9,998 more]", format!("{:02?}", Truncated::new(&v, 2)));
+    assert_eq!("[... 10,000 more]", format!("{:?}", Truncated::new(&v, 0)));
+    assert_eq!("[0, 1, 2]", format!("{:?}", Truncated::new(&v[..3], 3)));
+    assert_eq!("[0, 1, 2]", format!("{:?}", Truncated::new(&v[..3], 5)));
+    assert_eq!("[]", format!("{:?}", Truncated::new(&v[..0], 5)));
+    assert_eq!("[0, ... 1,234,566 more]", format!("{:?}", Truncated::new(0..1234567, 1)));
+}
+
+#[test]
+fn nested() {
+    let v = vec![vec![1, 2, 3], vec![4]];
+
+    assert_eq!(
+        "[\n    [\n        1,\n        2,\n        3,\n    ],\n    ... 1 more,\n]",
+        format!("{:#?}", Truncated::new(&v, 1))
+    );
+}
+
+#[test]
+fn map() {
+    let map: BTreeMap<&str, Vec<u8>> =
+        vec![("a", vec![1]), ("b", vec![2]), ("c", vec![3])].into_iter().collect();
+
+    assert_eq!("{\"a\": [1], \"b\": [2], ... 1 more}", format!("{:?}", TruncatedMap::new(&map, 2)));
+    assert_eq!(
+        "{\n    \"a\": [\n        1,\n    ],\n    ... 2 more,\n}",
+        format!("{:#?}", TruncatedMap::new(&map, 1))
+    );
+    assert_eq!(format!("{:?}", map), format!("{:?}", TruncatedMap::new(&map, 3)));
+    assert_eq!(format!("{:#?}", map), format!("{:#?}", TruncatedMap::new(&map, 3)));
+}
+
+#[test]
+fn str() {
+    let s = "a\"b\nc".repeat(1000);
+
+    assert_eq!("\"a\\\"b\\nc\"... (5,000 bytes)", format!("{:?}", TruncatedStr::new(&s, 5)));
+    assert_eq!("\"a\\\"b\"... (5,000 bytes)", format!("{:#?}", TruncatedStr::new(&s, 3)));
+    assert_eq!("\"\"... (5,000 bytes)", format!("{:?}", TruncatedStr::new(&s, 0)));
+    assert_eq!(format!("{:?}", &s[..5]), format!("{:?}", TruncatedStr::new(&s[..5], 5)));
+    assert_eq!(format!("{:?}", s), format!("{:?}", TruncatedStr::new(s.as_str(), 5000)));
+}
+
+#[test]
+fn str_char_boundary() {
+    // each char is 3 bytes long
+    let s = "中文字";
+
+    assert_eq!("\"\"... (9 bytes)", format!("{:?}", TruncatedStr::new(s, 2)));
+    assert_eq!("\"中\"... (9 bytes)", format!("{:?}", TruncatedStr::new(s, 3)));
+    assert_eq!("\"中\"... (9 bytes)", format!("{:?}", TruncatedStr::new(s, 5)));
+    assert_eq!("\"中文\"... (9 bytes)", format!("{:?}", TruncatedStr::new(s, 6)));
+    assert_eq!("\"中文字\"", format!("{:?}", TruncatedStr::new(String::from(s), 9)));
+}
+
+#[test]
+fn bytes() {
+    let b = vec![b'a'; 2048];
+
+    assert_eq!("b\"aaaa\"... (2,048 bytes)", format!("{:?}", TruncatedBytes::new(&b, 4)));
+    assert_eq!("b\"aaaa\"", format!("{:?}", TruncatedBytes::new(&b[..4], 4)));
+    assert_eq!("b\"\"", format!("{:?}", TruncatedBytes::new(b"", 4)));
+    assert_eq!(
+        "b\"\\t\\r\\n\\\\\\\"\\'\\x00\\x7f\\xff\"",
+        format!("{:?}", TruncatedBytes::new(b"\t\r\n\\\"'\x00\x7F\xFF", 16))
+    );
+}
+
+#[test]
+fn remaining() {
+    // an exact size hint is used instead of walking the remaining elements
+    #[cfg(target_pointer_width = "64")]
+    assert_eq!(
+        "[0, 1, ... 18,446,744,073,709,551,613 more]",
+        format!("{:?}", Truncated::new(0..u64::MAX, 2))
+    );
+    assert_eq!("[0, ... 4 more]", format!("{:?}", Truncated::new((0..10).filter(|i| i % 2 == 0), 1)));
+    assert_eq!(
+        "{0: 0, ... 4 more}",
+        format!("{:?}", TruncatedMap::new((0..10).filter(|i| i % 2 == 0).map(|i| (i, i)), 1))
+    );
+}
@@ -314,3 +314,225 @@ fn fake_tuple_struct() {
     assert_eq!("Outer(1.23######)", format!("{:#<10.2?}", outer));
     assert_eq!("Outer(\n    1.23######,\n)", format!("{:#<#10.2?}", outer));
 }
+
+#[test]
+#[allow(dead_code)]
+fn unit_renamed_literal_and_path() {
+    struct Outer(f64);
+
+    struct Outer2(f64);
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_tuple_struct!("FileHandle", f, self);
+        }
+    }
+
+    impl Debug for Outer2 {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_tuple_struct!(net::Socket, f, self);
+        }
+    }
+
+    let outer = Outer(1.23456789);
+    let outer_2 = Outer2(1.23456789);
+
+    assert_eq!("FileHandle", format!("{:?}", outer));
+    assert_eq!("FileHandle", format!("{:#?}", outer));
+
+    assert_eq!("net::Socket", format!("{:?}", outer_2));
+    assert_eq!("net::Socket", format!("{:#?}", outer_2));
+}
+
+#[test]
+fn all_renamed_literal_and_path() {
+    struct Outer(f64, u8);
+
+    struct Outer2(f64, u8);
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_tuple_struct!("FileHandle", f, self, .0, .1);
+        }
+    }
+
+    impl Debug for Outer2 {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_tuple_struct!(net::Socket, f, self, .0, .1);
+        }
+    }
+
+    let outer = Outer(1.23456789, 5);
+    let outer_2 = Outer2(1.23456789, 5);
+
+    assert_eq!("FileHandle(1.23456789, 5)", format!("{:?}", outer));
+    assert_eq!("FileHandle(\n    1.23456789,\n    5,\n)", format!("{:#?}", outer));
+    assert_eq!("FileHandle(0000001.23, 0000000005)", format!("{:010.2?}", outer));
+
+    assert_eq!("net::Socket(1.23456789, 5)", format!("{:?}", outer_2));
+    assert_eq!("net::Socket(\n    1.23456789,\n    5,\n)", format!("{:#?}", outer_2));
+    assert_eq!("net::Socket(0000001.23, 0000000005)", format!("{:010.2?}", outer_2));
+}
+
+#[test]
+fn redacted() {
+    struct Outer(&'static str, String, String);
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_tuple_struct!(Outer, f, self, .0, .1: redacted, .2: redacted(hash));
+        }
+    }
+
+    let outer = Outer("magiclen", String::from("password"), String::from("password"));
+
+    assert_eq!(
+        "Outer(\"magiclen\", <redacted>, <redacted #4B1A493507B3A318>)",
+        format!("{:?}", outer)
+    );
+    assert_eq!(
+        "Outer(\n    \"magiclen\",\n    <redacted>,\n    <redacted #4B1A493507B3A318>,\n)",
+        format!("{:#?}", outer)
+    );
+}
+
+#[test]
+fn conditional_fields() {
+    struct Outer(u8, Option<u8>, Vec<u8>, u32);
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_tuple_struct!(Outer, f, self, .0 if self.0 > 1, .1 unless none, .2 unless empty, (.3, "{}!", self.3) unless default);
+        }
+    }
+
+    let outer_1 = Outer(1, None, vec![], 0);
+    let outer_2 = Outer(2, Some(1), vec![1], 10);
+
+    assert_eq!("Outer", format!("{:?}", outer_1));
+    assert_eq!("Outer(2, Some(1), [1], 10!)", format!("{:?}", outer_2));
+    assert_eq!(
+        "Outer(\n    2,\n    Some(\n        1,\n    ),\n    [\n        1,\n    ],\n    10!,\n)",
+        format!("{:#?}", outer_2)
+    );
+}
+
+#[test]
+fn truncated() {
+    struct Outer(Vec<u32>, &'static [u8]);
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_tuple_struct!(Outer, f, self, .0: truncate(2), .1: truncate(2));
+        }
+    }
+
+    let outer = Outer((0..5).collect(), b"abc");
+
+    assert_eq!("Outer([0, 1, ... 3 more], [97, 98, ... 1 more])", format!("{:?}", outer));
+}
+
+#[test]
+fn truncated_str_and_bytes() {
+    struct Outer(&'static str, &'static [u8]);
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_tuple_struct!(Outer, f, self, .0: truncate_str(2), .1: truncate_bytes(2));
+        }
+    }
+
+    let outer = Outer("abc", b"\x00\x01\x02");
+
+    assert_eq!("Outer(\"ab\"... (3 bytes), b\"\\x00\\x01\"... (3 bytes))", format!("{:?}", outer));
+}
+
+#[test]
+fn radix() {
+    struct Outer(u16, [u8; 2]);
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_tuple_struct!(Outer, f, self, .0: hex(4), .1: binary(2) if self.0 > 0);
+        }
+    }
+
+    assert_eq!("Outer(0x00ff, [0b01, 0b10])", format!("{:?}", Outer(0xFF, [1, 2])));
+    assert_eq!("Outer(0x0000)", format!("{:?}", Outer(0, [1, 2])));
+}
+
+#[test]
+fn closure_fields() {
+    struct Outer(u8);
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_tuple_struct!(Outer, f, self, .0, let .1 = |f| write!(f, "{}%", self.0));
+        }
+    }
+
+    assert_eq!("Outer(5, 5%)", format!("{:?}", Outer(5)));
+}
+
+#[test]
+#[allow(dead_code)]
+fn checked_fields() {
+    struct Outer(u8, &'static str, Option<u8>);
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_tuple_struct!(Self, f, self, .0, skip(.1), .2 unless none);
+        }
+    }
+
+    assert_eq!("Outer(1, Some(3))", format!("{:?}", Outer(1, "Hi", Some(3))));
+    assert_eq!("Outer(1)", format!("{:?}", Outer(1, "Hi", None)));
+    assert_eq!(
+        "Outer(\n    1,\n    Some(\n        3,\n    ),\n)",
+        format!("{:#?}", Outer(1, "Hi", Some(3)))
+    );
+}
+
+#[test]
+fn checked_fields_with_names_and_ghosts() {
+    struct Outer(u8, u8);
+
+    impl Debug for Outer {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_tuple_struct!(Renamed, f, self, (.0, "{:02}", self.0), skip(.1), let .2 = self.0 + self.1);
+        }
+    }
+
+    assert_eq!("Renamed(01, 3)", format!("{:?}", Outer(1, 2)));
+}
+
+#[test]
+fn generic() {
+    struct Outer<'a, T>(&'a T);
+
+    impl<'a, T: Debug> Debug for Outer<'a, T> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            if f.alternate() {
+                debug_helper::impl_debug_for_tuple_struct!(Self, f, self, .0);
+            } else {
+                debug_helper::impl_debug_for_tuple_struct!(Outer<'a, T>, f, self, .0);
+            }
+        }
+    }
+
+    struct Wrapper<T>(T);
+
+    impl<T: Debug> Debug for Wrapper<T> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            debug_helper::impl_debug_for_tuple_struct!(Self, f, self, .0);
+        }
+    }
+
+    let outer = Outer(&5u8);
+
+    assert_eq!("Outer(5)", format!("{:?}", outer));
+    assert_eq!("Outer<u8>(\n    5,\n)", format!("{:#?}", outer));
+
+    assert_eq!("Wrapper<Option<u8>>(Some(5))", format!("{:?}", Wrapper(Some(5u8))));
+    assert_eq!("Wrapper<u8>(5)", format!("{:?}", Wrapper(5u8)));
+}
@@ -0,0 +1,220 @@
+use std::fmt::{self, Debug, Formatter};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Int,
+    Float,
+    Bytes,
+    Unknown,
+}
+
+#[derive(Clone, Copy)]
+union Value {
+    int:   u32,
+    float: f32,
+    bytes: [u8; 4],
+}
+
+struct Tagged {
+    kind:  Kind,
+    value: Value,
+}
+
+impl Debug for Tagged {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        let value = &self.value;
+
+        debug_helper::impl_debug_for_union!(Value, f, value, unsafe match self.kind {
+            Kind::Int => .int,
+            Kind::Float => .float as "f",
+            Kind::Bytes => .bytes: hex(2),
+            Kind::Unknown => opaque,
+        });
+    }
+}
+
+#[test]
+fn active_field() {
+    let int = Tagged {
+        kind:  Kind::Int,
+        value: Value {
+            int: 5
+        },
+    };
+    let float = Tagged {
+        kind:  Kind::Float,
+        value: Value {
+            float: 1.5
+        },
+    };
+    let bytes = Tagged {
+        kind:  Kind::Bytes,
+        value: Value {
+            bytes: [1, 2, 254, 255]
+        },
+    };
+
+    assert_eq!("Value { int: 5 }", format!("{:?}", int));
+    assert_eq!("Value {\n    int: 5,\n}", format!("{:#?}", int));
+    assert_eq!("Value { f: 1.50 }", format!("{:.2?}", float));
+    assert_eq!("Value { bytes: [0x01, 0x02, 0xfe, 0xff] }", format!("{:?}", bytes));
+}
+
+#[test]
+fn opaque() {
+    let unknown = Tagged {
+        kind:  Kind::Unknown,
+        value: Value {
+            bytes: [1, 2, 254, 255]
+        },
+    };
+
+    assert_eq!("Value([0102feff])", format!("{:?}", unknown));
+    assert_eq!(
+        "Value(\n    [\n        00000000: 0102 feff                                ....\n    ],\n)",
+        format!("{:#?}", unknown)
+    );
+}
+
+#[test]
+fn selector() {
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    union Number {
+        signed:   i16,
+        unsigned: u16,
+    }
+
+    impl Debug for Number {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            let is_signed = |f: &Formatter<'_>| f.sign_minus();
+
+            debug_helper::impl_debug_for_union!(Self, f, self, unsafe match is_signed(f) {
+                true => .signed,
+                false => .unsigned,
+            });
+        }
+    }
+
+    let n = Number {
+        signed: -1
+    };
+
+    assert_eq!("Number { unsigned: 65535 }", format!("{:?}", n));
+    assert_eq!("Number { signed: -1 }", format!("{:-?}", n));
+}
+
+#[test]
+fn impl_block() {
+    #[derive(Clone, Copy)]
+    union Raw {
+        word:  u16,
+        bytes: [u8; 2],
+    }
+
+    debug_helper::derive_debug_for_union!(Raw, self, unsafe opaque);
+
+    #[derive(Clone, Copy)]
+    #[allow(dead_code)]
+    union Generic<T: Copy> {
+        value: T,
+        bytes: [u8; 2],
+    }
+
+    debug_helper::derive_debug_for_union! {
+        impl<T: Copy + Debug> Generic<T> {
+            Self, self, unsafe match () {
+                _ => .value,
+            }
+        }
+    }
+
+    assert_eq!(
+        "Raw([0102])",
+        format!("{:?}", Raw {
+            bytes: [1, 2]
+        })
+    );
+    assert_eq!(
+        "Raw([0102])",
+        format!("{:?}", Raw {
+            word: u16::from_ne_bytes([1, 2])
+        })
+    );
+    assert_eq!(
+        "Generic<u16> { value: 5 }",
+        format!("{:?}", Generic {
+            value: 5u16
+        })
+    );
+}
+
+#[test]
+fn opaque_with_fields_of_different_sizes() {
+    #[derive(Clone, Copy)]
+    #[allow(dead_code)]
+    union Small {
+        byte: u8,
+        word: u32,
+    }
+
+    struct Tagged {
+        is_word: bool,
+        value:   Small,
+    }
+
+    impl Debug for Tagged {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            let value = &self.value;
+
+            // only the `word` field covers every byte of the union
+            debug_helper::impl_debug_for_union!(Small, f, value, unsafe match self.is_word {
+                true => opaque,
+                false => .byte,
+            });
+        }
+    }
+
+    let byte = Tagged {
+        is_word: false,
+        value:   Small {
+            byte: 1
+        },
+    };
+    let word = Tagged {
+        is_word: true,
+        value:   Small {
+            word: u32::from_ne_bytes([1, 2, 3, 4])
+        },
+    };
+
+    assert_eq!("Small { byte: 1 }", format!("{:?}", byte));
+    assert_eq!("Small([01020304])", format!("{:?}", word));
+}
+
+#[test]
+fn value_outside_of_unsafe() {
+    #[derive(Clone, Copy)]
+    union Number {
+        int: u32,
+    }
+
+    struct Ptr(*const Number);
+
+    impl Debug for Ptr {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+            // the macro does not cover the dereference of the raw pointer
+            let value = unsafe { &*self.0 };
+
+            debug_helper::impl_debug_for_union!(Number, f, value, unsafe match () {
+                _ => .int,
+            });
+        }
+    }
+
+    let n = Number {
+        int: 7
+    };
+
+    assert_eq!("Number { int: 7 }", format!("{:?}", Ptr(&n)));
+}